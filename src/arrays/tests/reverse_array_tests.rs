@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod reverse_array_tests {
+    use std::num::NonZeroU32;
     use crate::arrays::reverse_array::reverse_array;
 
     #[test]
@@ -58,4 +59,12 @@ mod reverse_array_tests {
         reverse_array(&mut float_arr);
         assert_eq!(float_arr, [3.3, 2.2, 1.1]);
     }
+
+    #[test]
+    fn test_reverse_array_with_non_default_values() {
+        let mut arr: Vec<NonZeroU32> = [1, 2, 3, 4, 5].iter().map(|&n| NonZeroU32::new(n).unwrap()).collect();
+        reverse_array(&mut arr);
+        let reversed: Vec<u32> = arr.iter().map(|n| n.get()).collect();
+        assert_eq!(reversed, vec![5, 4, 3, 2, 1]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+use std::env;
+use std::error::Error;
+use std::process;
+use std::str::FromStr;
+use rust_dsa::sorting::registry::{find_sort_algorithm, sort_algorithms, SortAlgorithm};
+use rust_dsa::util::benchmark::BenchmarkRunner;
+use rust_dsa::util::data_generation::{distributions, find_distribution, Distribution};
+use rust_dsa::util::reporting::{create_benchmark_plot, write_benchmark_records};
+
+const USAGE: &str = "\
+Usage: cargo run --release --bin benchmark -- [options]
+
+Options:
+  --algorithms <names>     Comma-separated algorithm names (default: all)
+  --distributions <names>  Comma-separated input distributions (default: random)
+  --sizes <sizes>          Comma-separated input sizes (default: 1000,2000,5000,10000)
+  --warmup <runs>          Untimed runs before each measurement (default: 3)
+  --iterations <runs>      Timed runs per measurement (default: 30)
+  --seed <seed>            Seed for the generated inputs (default: random)
+  --name <name>            Base name of the files written to target/benchmarks (default: benchmark)
+  --list                   List the available algorithms and distributions
+  --help                   Show this message";
+
+struct Options {
+    algorithms: Vec<SortAlgorithm<i32>>,
+    distributions: Vec<Distribution>,
+    sizes: Vec<usize>,
+    runner: BenchmarkRunner,
+    name: String,
+}
+
+fn split(list: &str) -> impl Iterator<Item = &str> {
+    list.split(',').map(str::trim).filter(|item| !item.is_empty())
+}
+
+fn parse_number<N: FromStr>(flag: &str, value: &str) -> Result<N, String> {
+    value.parse().map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn parse_options(args: &[String]) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        algorithms: sort_algorithms(),
+        distributions: vec![find_distribution("random").unwrap()],
+        sizes: vec![1000, 2000, 5000, 10000],
+        runner: BenchmarkRunner::new(),
+        name: "benchmark".to_string(),
+    };
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return Ok(None);
+            }
+            "--list" => {
+                println!("Algorithms:");
+                for algorithm in sort_algorithms::<i32>() {
+                    println!("  {} (stable: {}, in place: {}, average: {})", algorithm.name, algorithm.stable, algorithm.in_place, algorithm.average_case);
+                }
+                println!("Distributions:");
+                for distribution in distributions() {
+                    println!("  {}", distribution.name);
+                }
+                return Ok(None);
+            }
+            _ => {}
+        }
+
+        let value = args.next().ok_or_else(|| format!("{} expects a value", flag))?;
+        match flag.as_str() {
+            "--algorithms" => {
+                options.algorithms = split(value)
+                    .map(|name| find_sort_algorithm(name).ok_or_else(|| format!("unknown algorithm '{}', see --list", name)))
+                    .collect::<Result<_, _>>()?;
+            }
+            "--distributions" => {
+                options.distributions = split(value)
+                    .map(|name| find_distribution(name).ok_or_else(|| format!("unknown distribution '{}', see --list", name)))
+                    .collect::<Result<_, _>>()?;
+            }
+            "--sizes" => {
+                options.sizes = split(value).map(|size| parse_number(flag, size)).collect::<Result<_, _>>()?;
+            }
+            "--warmup" => options.runner = options.runner.warmup(parse_number(flag, value)?),
+            "--iterations" => options.runner = options.runner.iterations(parse_number(flag, value)?),
+            "--seed" => options.runner = options.runner.seed(parse_number(flag, value)?),
+            "--name" => options.name = value.clone(),
+            _ => return Err(format!("unknown option '{}'\n\n{}", flag, USAGE)),
+        }
+    }
+
+    Ok(Some(options))
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_options(&args) {
+        Ok(Some(options)) => options,
+        Ok(None) => return Ok(()),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(2);
+        }
+    };
+
+    println!("Seed: {}", options.runner.get_seed());
+    println!("{:<28} {:<20} {:>8} {:>14} {:>14} {:>14} {:>14}", "algorithm", "distribution", "size", "median (ns)", "95% CI low", "95% CI high", "comparisons");
+    let records = options.runner.run_all(&options.algorithms, &options.distributions, &options.sizes, |record| {
+        println!(
+            "{:<28} {:<20} {:>8} {:>14} {:>14} {:>14} {:>14}",
+            record.algorithm,
+            record.distribution,
+            record.size,
+            record.duration_p50.as_nanos(),
+            record.duration_ci_low.as_nanos(),
+            record.duration_ci_high.as_nanos(),
+            record.comparisons,
+        );
+    });
+
+    write_benchmark_records(&records, &options.name)?;
+    for distribution in &options.distributions {
+        let filename = format!("{}_{}.png", options.name, distribution.name.replace(' ', "_"));
+        let title = format!("Sorting {} input", distribution.name);
+        create_benchmark_plot(&records, distribution.name, &filename, &title)?;
+    }
+    println!("Results written to target/benchmarks/{0}.json, {0}.csv and {0}_<distribution>.png", options.name);
+
+    Ok(())
+}
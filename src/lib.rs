@@ -1,6 +1,16 @@
 pub mod util {
     pub mod data_generation;
     pub mod reporting;
+    pub mod benchmark;
+    pub mod visualization;
+
+    #[cfg(test)]
+    mod tests {
+        mod reporting_tests;
+        mod benchmark_tests;
+        mod data_generation_tests;
+        mod visualization_tests;
+    }
 }
 
 pub mod arrays {
@@ -47,13 +57,31 @@ pub mod trees {
 }
 
 pub mod sorting {
+    pub mod compare;
+    pub mod counted;
+    pub mod tracker;
     pub mod sort_tracker;
+    pub mod sort_trace;
+    pub mod timing_span;
+
+    pub mod selection_sort;
+    pub mod bubble_sort;
+    pub mod cocktail_shaker_sort;
+    pub mod gnome_sort;
+    pub mod odd_even_sort;
+    pub mod cycle_sort;
 
     pub mod insertion_sort;
     pub mod insertion_sort_with_tracking;
+    pub mod shell_sort;
 
     pub mod merge_sort;
     pub mod merge_sort_with_tracking;
+    pub mod bottom_up_merge_sort;
+    pub mod parallel_merge_sort;
+    pub mod natural_merge_sort;
+    pub mod tim_sort;
+    pub mod external_sort;
 
     pub mod heap_sort;
     pub mod heap_sort_with_tracking;
@@ -61,19 +89,53 @@ pub mod sorting {
     pub mod quick_sort;
     pub mod quick_sort_mid;
     pub mod quick_sort_mod;
+    pub mod quick_sort_3way;
+    pub mod dual_pivot_quick_sort;
+    pub mod parallel_quick_sort;
     pub mod quick_sort_with_tracking;
     pub mod quick_sort_mid_with_tracking;
     pub mod quick_sort_mod_with_tracking;
 
+    pub mod introsort;
+
+    pub mod selection;
+
+    pub mod radix_key;
+    pub mod counting_sort;
+    pub mod radix_sort;
+    pub mod bucket_sort;
+
     pub mod graph_topological_sort;
 
+    pub mod registry;
+
     #[cfg(test)]
     mod tests {
+        mod tracker_tests;
+        mod counted_tests;
+        mod sort_trace_tests;
+        mod timing_span_tests;
+        mod differential_tests;
+        mod registry_tests;
+
+        mod selection_sort_tests;
+        mod bubble_sort_tests;
+        mod cocktail_shaker_sort_tests;
+        mod gnome_sort_tests;
+        mod odd_even_sort_tests;
+        mod cycle_sort_tests;
+
         mod insertion_sort_tests;
         mod insertion_sort_with_tracking_tests;
+        mod shell_sort_tests;
 
         mod merge_sort_tests;
         mod merge_sort_with_tracking_tests;
+        mod bottom_up_merge_sort_tests;
+        mod parallel_merge_sort_tests;
+        mod natural_merge_sort_tests;
+        mod tim_sort_tests;
+        mod external_sort_tests;
 
         mod heap_sort_tests;
         mod heap_sort_with_tracking_tests;
@@ -81,15 +143,20 @@ pub mod sorting {
         mod quick_sort_tests;
         mod quick_sort_mid_tests;
         mod quick_sort_mod_tests;
+        mod quick_sort_3way_tests;
+        mod dual_pivot_quick_sort_tests;
+        mod parallel_quick_sort_tests;
         mod quick_sort_with_tracking_tests;
         mod quick_sort_mid_with_tracking_tests;
         mod quick_sort_mod_with_tracking_tests;
 
-        mod insertion_sort_benchmarks;
-        mod merge_sort_benchmarks;
-        mod heap_sort_benchmarks;
-        mod quick_sort_benchmarks;
-        mod quick_sort_mod_benchmarks;
+        mod introsort_tests;
+
+        mod selection_tests;
+
+        mod counting_sort_tests;
+        mod radix_sort_tests;
+        mod bucket_sort_tests;
 
         mod graph_topological_sort_tests;
     }
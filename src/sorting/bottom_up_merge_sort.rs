@@ -0,0 +1,89 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn bottom_up_merge_sort<T: PartialOrd + Clone>(arr: &mut [T]) {
+    bottom_up_merge_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn bottom_up_merge_sort_by<T: Clone, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    bottom_up_merge_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn bottom_up_merge_sort_by_key<T: Clone, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    bottom_up_merge_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn bottom_up_merge_sort_tracked<T: PartialOrd + Clone, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    bottom_up_merge_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn bottom_up_merge_sort_by_key_tracked<T: Clone, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    bottom_up_merge_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Merges runs of width 1, 2, 4, ... without recursion. A buffer of `n`
+// elements is allocated once, and each pass merges the runs from one of `arr`
+// and the buffer into the other, so every pass moves each element once. If
+// the last pass leaves the result in the buffer, it is swapped back into
+// `arr`. The buffer starts as a clone of `arr`, whose contents the first pass
+// overwrites, which is why `T` must be `Clone`.
+//
+// The elements move through the buffer rather than by swaps within `arr`, so
+// a trace of the sort records its comparisons, at positions within the pass's
+// source, but cannot be replayed.
+pub fn bottom_up_merge_sort_by_tracked<T: Clone, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let size = arr.len();
+    if size > 1 {
+        let mut buffer: Vec<T> = arr.to_vec();
+        let buffer_bytes = size_of_val(buffer.as_slice());
+        tracker.record_auxiliary_memory(buffer_bytes);
+
+        let mut in_buffer = false;
+        let mut width: usize = 1;
+
+        while width < size {
+            let (source, target): (&[T], &mut [T]) = if in_buffer { (&buffer, arr) } else { (arr, &mut buffer) };
+            for left_first in (0..size).step_by(2 * width) {
+                let right_first = (left_first + width).min(size);
+                let right_end = (left_first + 2 * width).min(size);
+                merge(source, target, left_first, right_first, right_end, &mut compare, tracker);
+            }
+
+            in_buffer = !in_buffer;
+            width *= 2;
+        }
+
+        if in_buffer {
+            for _ in 0..size {
+                tracker.record_read();
+                tracker.record_move();
+            }
+            arr.swap_with_slice(&mut buffer);
+        }
+        tracker.record_auxiliary_memory_freed(buffer_bytes);
+    }
+
+    tracker.stop_timer();
+}
+
+// Merges the runs `source[left_first..right_first]` and
+// `source[right_first..right_end]` into the same positions of `target`.
+fn merge<T: Clone, F: FnMut(&T, &T) -> Ordering, K: Tracker>(source: &[T], target: &mut [T], left_first: usize, right_first: usize, right_end: usize, compare: &mut F, tracker: &mut K) {
+    let mut left_position: usize = left_first;
+    let mut right_position: usize = right_first;
+
+    for target_element in &mut target[left_first..right_end] {
+        let take_left = right_position == right_end
+            || (left_position < right_first
+                && tracker.compare_by(source, left_position, right_position, compare) != Ordering::Greater);
+
+        let position = if take_left { &mut left_position } else { &mut right_position };
+        tracker.record_read();
+        tracker.record_move();
+        *target_element = source[*position].clone();
+        *position += 1;
+    }
+}
@@ -0,0 +1,44 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn bubble_sort<T: PartialOrd>(arr: &mut [T]) {
+    bubble_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn bubble_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    bubble_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn bubble_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    bubble_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn bubble_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    bubble_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn bubble_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    bubble_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Bubbles the largest remaining element to the end of the unsorted part on
+// each pass. Everything after the last swap of a pass is already in place, so
+// the next pass stops there, and a pass without swaps ends the sort early.
+pub fn bubble_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let mut unsorted_end = arr.len();
+    while unsorted_end > 1 {
+        let mut last_swap: usize = 0;
+        for j in 1..unsorted_end {
+            if tracker.compare_by(arr, j - 1, j, &mut compare) == Ordering::Greater {
+                tracker.swap(arr, j - 1, j);
+                last_swap = j;
+            }
+        }
+        unsorted_end = last_swap;
+    }
+
+    tracker.stop_timer();
+}
@@ -0,0 +1,93 @@
+use crate::sorting::compare::partial_compare;
+use crate::sorting::insertion_sort::insertion_sort_untimed;
+use crate::sorting::merge_sort::{apply_order, merge_sort_untimed};
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn bucket_sort<T: Copy + Into<f64>>(arr: &mut [T]) {
+    bucket_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn bucket_sort_by_key<T, F: FnMut(&T) -> f64>(arr: &mut [T], key: F) {
+    bucket_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn bucket_sort_tracked<T: Copy + Into<f64>, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    bucket_sort_by_key_tracked(arr, |&value| value.into(), tracker);
+}
+
+// Spreads positions over one bucket per element in proportion to where their
+// key falls between the smallest and largest key, moves the elements into
+// bucket order, then insertion sorts each bucket in place. For uniformly
+// distributed keys the buckets hold O(1) elements on average, giving linear
+// time; skewed keys degrade towards insertion sort.
+// `NaN` keys compare equal to everything and are placed in the last bucket.
+// If the keys span an infinite range, or one too wide for an `f64`, they
+// cannot be spread over the buckets, and the input is merge sorted instead
+// with `NaN` keys last.
+pub fn bucket_sort_by_key_tracked<T, F: FnMut(&T) -> f64, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let size = arr.len();
+    if size > 1 {
+        let keys: Vec<f64> = arr.iter().map(&mut key).collect();
+        keys.iter().for_each(|_| tracker.record_read());
+        let min = keys.iter().copied().filter(|k| !k.is_nan()).fold(f64::INFINITY, f64::min);
+        let max = keys.iter().copied().filter(|k| !k.is_nan()).fold(f64::NEG_INFINITY, f64::max);
+        if !(max - min).is_finite() {
+            let mut compare = |a: &T, b: &T| {
+                let (a, b) = (key(a), key(b));
+                partial_compare(&a.is_nan(), &b.is_nan()).then(partial_compare(&a, &b))
+            };
+            merge_sort_untimed(arr, &mut compare, tracker);
+            tracker.stop_timer();
+            return;
+        }
+        let scale = size as f64 / (max - min);
+
+        // The float-to-integer cast saturates and maps `NaN` to 0, so equal
+        // keys fall into the first bucket.
+        let bucket_of = |k: f64| {
+            if k.is_nan() {
+                size - 1
+            } else {
+                (((k - min) * scale) as usize).min(size - 1)
+            }
+        };
+
+        let mut counts: Vec<usize> = vec![0; size + 1];
+        let mut order: Vec<usize> = vec![0; size];
+        tracker.record_auxiliary_memory(size * size_of::<f64>() + (2 * size + 1) * size_of::<usize>());
+
+        tracker.enter_span("distribute");
+        for &k in &keys {
+            counts[bucket_of(k) + 1] += 1;
+        }
+        for i in 1..counts.len() {
+            counts[i] += counts[i - 1];
+        }
+
+        tracker.record_pass();
+        for (position, &k) in keys.iter().enumerate() {
+            let bucket = bucket_of(k);
+            order[counts[bucket]] = position;
+            counts[bucket] += 1;
+            tracker.record_bucket_write();
+        }
+
+        apply_order(arr, &mut order, tracker);
+        tracker.exit_span();
+
+        // `counts[bucket]` now holds the end of each bucket.
+        tracker.enter_span("sort buckets");
+        let mut compare = |a: &T, b: &T| partial_compare(&key(a), &key(b));
+        let mut bucket_start: usize = 0;
+        for &bucket_end in &counts[..size] {
+            insertion_sort_untimed(&mut arr[bucket_start..bucket_end], &mut compare, &mut tracker.offset(bucket_start));
+            bucket_start = bucket_end;
+        }
+        tracker.exit_span();
+        tracker.record_auxiliary_memory_freed(size * size_of::<f64>() + (2 * size + 1) * size_of::<usize>());
+    }
+
+    tracker.stop_timer();
+}
@@ -0,0 +1,57 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn cocktail_shaker_sort<T: PartialOrd>(arr: &mut [T]) {
+    cocktail_shaker_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn cocktail_shaker_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    cocktail_shaker_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn cocktail_shaker_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    cocktail_shaker_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn cocktail_shaker_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    cocktail_shaker_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn cocktail_shaker_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    cocktail_shaker_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Bubble sort that alternates direction: a forward pass carries the largest
+// element to the end, then a backward pass carries the smallest to the front.
+// Small elements near the end ("turtles") therefore move quickly as well.
+pub fn cocktail_shaker_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let mut start: usize = 0;
+    let mut end: usize = arr.len();
+    while end - start > 1 {
+        let mut last_swap = start;
+        for j in (start + 1)..end {
+            if tracker.compare_by(arr, j - 1, j, &mut compare) == Ordering::Greater {
+                tracker.swap(arr, j - 1, j);
+                last_swap = j;
+            }
+        }
+        end = last_swap;
+        if end - start <= 1 {
+            break;
+        }
+
+        last_swap = end;
+        for j in ((start + 1)..end).rev() {
+            if tracker.compare_by(arr, j - 1, j, &mut compare) == Ordering::Greater {
+                tracker.swap(arr, j - 1, j);
+                last_swap = j;
+            }
+        }
+        start = last_swap;
+    }
+
+    tracker.stop_timer();
+}
@@ -0,0 +1,8 @@
+use std::cmp::Ordering;
+
+/// Orders two values by their `PartialOrd` implementation, treating
+/// incomparable values (such as `NaN`) as equal.
+#[inline]
+pub fn partial_compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Arc;
+use crate::sorting::tracker::Tracker;
+
+/// A shared count of the comparisons made between `Counted` values.
+///
+/// Clones share the same count, so a counter can wrap values handed to any
+/// algorithm, including `slice::sort`, `BinaryHeap` and the heaps in
+/// `trees::heaps`, and read back how many comparisons it made. The count is
+/// atomic so the parallel sorts can be measured too.
+#[derive(Debug, Clone, Default)]
+pub struct ComparisonCounter {
+    count: Arc<AtomicUsize>,
+}
+
+impl ComparisonCounter {
+    pub fn new() -> Self {
+        ComparisonCounter { count: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    pub fn wrap<T>(&self, value: T) -> Counted<T> {
+        Counted { value, counter: self.clone() }
+    }
+
+    pub fn wrap_all<T, I: IntoIterator<Item = T>>(&self, values: I) -> Vec<Counted<T>> {
+        values.into_iter().map(|value| self.wrap(value)).collect()
+    }
+
+    /// Returns the comparisons counted since the counter was created or last
+    /// reset.
+    pub fn count(&self) -> usize {
+        self.count.load(atomic::Ordering::Relaxed)
+    }
+
+    pub fn reset(&self) {
+        self.count.store(0, atomic::Ordering::Relaxed);
+    }
+
+    /// Runs `run` and returns its result with the number of comparisons it
+    /// made, leaving comparisons counted before it untouched.
+    pub fn measure<R, F: FnOnce() -> R>(&self, run: F) -> (R, usize) {
+        let before = self.count();
+        let result = run();
+        (result, self.count() - before)
+    }
+
+    /// Records the comparisons counted so far on `tracker`, so they add up
+    /// with the comparisons a `SortTracker` counts itself, and resets the
+    /// count.
+    pub fn record_into<K: Tracker>(&self, tracker: &mut K) {
+        let comparisons = self.count.swap(0, atomic::Ordering::Relaxed);
+        for _ in 0..comparisons {
+            tracker.record_comparison();
+        }
+    }
+
+    fn increment(&self) {
+        self.count.fetch_add(1, atomic::Ordering::Relaxed);
+    }
+}
+
+/// A value whose comparisons are counted by a `ComparisonCounter`.
+///
+/// Every call to `cmp`, `partial_cmp` or `eq`, and the operators built on
+/// them, counts as one comparison. Hashing, cloning and formatting only touch
+/// the wrapped value and are not counted.
+#[derive(Clone)]
+pub struct Counted<T> {
+    value: T,
+    counter: ComparisonCounter,
+}
+
+impl<T> Counted<T> {
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Ord> Ord for Counted<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.counter.increment();
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Counted<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.counter.increment();
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Counted<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counter.increment();
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Counted<T> {}
+
+impl<T: Hash> Hash for Counted<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Counted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::sorting::merge_sort::apply_order;
+use crate::sorting::radix_key::RadixKey;
+use crate::sorting::radix_sort::lsd_radix_sort_keys_untimed;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn counting_sort<T: RadixKey>(arr: &mut [T]) {
+    counting_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn counting_sort_by_key<T, B: RadixKey, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    counting_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn counting_sort_tracked<T: RadixKey, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    counting_sort_by_key_tracked(arr, |&value| value, tracker);
+}
+
+// The widest key range counted directly, as a multiple of the input length.
+// Wider ranges would spend more time and memory on the count table than on
+// the elements.
+const MAX_RANGE_PER_ELEMENT: usize = 16;
+
+// Counts how many keys fall on each value between the smallest and largest
+// key, then places every position directly after the positions of all smaller
+// keys. The count table has one entry per value in that range, so this is only
+// meant for keys drawn from a small range. When the range is wider than
+// `MAX_RANGE_PER_ELEMENT` times the input length, the keys already read are
+// handed to `lsd_radix_sort` instead. Equal keys keep their order.
+pub fn counting_sort_by_key_tracked<T, B: RadixKey, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    if arr.len() > 1 {
+        let keys: Vec<u64> = arr.iter().map(|value| key(value).to_ordered_bits()).collect();
+        keys.iter().for_each(|_| tracker.record_read());
+        let min = *keys.iter().min().unwrap();
+        let max = *keys.iter().max().unwrap();
+        let range = usize::try_from(max - min).ok().and_then(|span| span.checked_add(1));
+        let range = match range {
+            Some(range) if range <= arr.len().saturating_mul(MAX_RANGE_PER_ELEMENT) => range,
+            _ => {
+                lsd_radix_sort_keys_untimed(arr, &keys, B::BYTES, tracker);
+                tracker.stop_timer();
+                return;
+            }
+        };
+
+        let mut counts: Vec<usize> = vec![0; range];
+        let mut order: Vec<usize> = vec![0; arr.len()];
+        let auxiliary_memory = keys.len() * size_of::<u64>() + counts.len() * size_of::<usize>() + order.len() * size_of::<usize>();
+        tracker.record_auxiliary_memory(auxiliary_memory);
+
+        for &k in &keys {
+            counts[(k - min) as usize] += 1;
+        }
+        let mut start = 0;
+        for count in counts.iter_mut() {
+            let bucket_size = *count;
+            *count = start;
+            start += bucket_size;
+        }
+
+        tracker.record_pass();
+        for (position, &k) in keys.iter().enumerate() {
+            let bucket = (k - min) as usize;
+            order[counts[bucket]] = position;
+            counts[bucket] += 1;
+            tracker.record_bucket_write();
+        }
+
+        apply_order(arr, &mut order, tracker);
+        tracker.record_auxiliary_memory_freed(auxiliary_memory);
+    }
+
+    tracker.stop_timer();
+}
@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn cycle_sort<T: PartialOrd>(arr: &mut [T]) {
+    cycle_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn cycle_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    cycle_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn cycle_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    cycle_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn cycle_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    cycle_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn cycle_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    cycle_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Rotates each cycle of the permutation into place. The element at
+// `cycle_start` is placed by counting the elements smaller than it, which
+// displaces another element back to `cycle_start`, until the cycle closes.
+// Every element is written at most once, at its final position, and the
+// tracker records exactly those writes. Not stable.
+pub fn cycle_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    for cycle_start in 0..arr.len().saturating_sub(1) {
+        let mut displaced = false;
+
+        loop {
+            let mut position = cycle_start;
+            for i in (cycle_start + 1)..arr.len() {
+                if tracker.compare_by(arr, i, cycle_start, &mut compare) == Ordering::Less {
+                    position += 1;
+                }
+            }
+            if position == cycle_start {
+                break;
+            }
+
+            // Skip past equal elements that were already placed.
+            while tracker.compare_by(arr, position, cycle_start, &mut compare) == Ordering::Equal {
+                position += 1;
+            }
+
+            tracker.swap(arr, cycle_start, position);
+            tracker.record_write(position);
+            displaced = true;
+        }
+
+        // The element that closed the cycle now sits at `cycle_start`.
+        if displaced {
+            tracker.record_write(cycle_start);
+        }
+    }
+
+    tracker.stop_timer();
+}
@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::insertion_sort::insertion_sort_untimed;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn dual_pivot_quick_sort<T: PartialOrd>(arr: &mut [T], cutoff: usize) {
+    dual_pivot_quick_sort_tracked(arr, cutoff, &mut NoopTracker);
+}
+
+pub fn dual_pivot_quick_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], cutoff: usize, compare: F) {
+    dual_pivot_quick_sort_by_tracked(arr, cutoff, compare, &mut NoopTracker);
+}
+
+pub fn dual_pivot_quick_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], cutoff: usize, key: F) {
+    dual_pivot_quick_sort_by_key_tracked(arr, cutoff, key, &mut NoopTracker);
+}
+
+pub fn dual_pivot_quick_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], cutoff: usize, tracker: &mut K) {
+    dual_pivot_quick_sort_by_tracked(arr, cutoff, partial_compare, tracker);
+}
+
+pub fn dual_pivot_quick_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], cutoff: usize, mut key: F, tracker: &mut K) {
+    dual_pivot_quick_sort_by_tracked(arr, cutoff, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn dual_pivot_quick_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], cutoff: usize, mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    if !arr.is_empty() {
+        quick_sort_recursive(arr, 0, arr.len() - 1, cutoff, &mut compare, tracker);
+    }
+    tracker.stop_timer();
+}
+
+fn quick_sort_recursive<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut low_index: usize, mut high_index: usize, cutoff: usize, compare: &mut F, tracker: &mut K) {
+    while low_index < high_index {
+        if high_index - low_index < cutoff {
+            insertion_sort_untimed(&mut arr[low_index..=high_index], compare, &mut tracker.offset(low_index));
+            return;
+        }
+
+        let (left_pivot, right_pivot) = partition(arr, low_index, high_index, compare, tracker);
+
+        // Recurse into the two smaller parts and loop on the largest so the
+        // stack depth stays O(log n).
+        let parts = [
+            (low_index, left_pivot),
+            (left_pivot + 1, right_pivot),
+            (right_pivot + 1, high_index + 1),
+        ];
+        let largest = (0..parts.len()).max_by_key(|&part| parts[part].1 - parts[part].0).unwrap();
+
+        for (part, &(first, end)) in parts.iter().enumerate() {
+            if part != largest && end > first + 1 {
+                quick_sort_recursive(arr, first, end - 1, cutoff, compare, tracker);
+            }
+        }
+
+        let (first, end) = parts[largest];
+        if end <= first + 1 {
+            return;
+        }
+        low_index = first;
+        high_index = end - 1;
+    }
+}
+
+// Yaroslavskiy's dual-pivot partition. Returns the final positions of the two
+// pivots `p <= q`; elements left of `p` are less than it, elements right of
+// `q` are greater than it and everything between lies in `[p, q]`.
+fn partition<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> (usize, usize) {
+    // Take the pivots from the tertiles rather than the ends so that sorted
+    // and reverse-sorted input still split evenly.
+    let third = (high_index - low_index) / 3;
+    if third > 0 {
+        tracker.swap(arr, low_index, low_index + third);
+        tracker.swap(arr, high_index, high_index - third);
+    }
+
+    if tracker.compare_by(arr, low_index, high_index, compare) == Ordering::Greater {
+        tracker.swap(arr, low_index, high_index);
+    }
+
+    let mut less_wall: usize = low_index + 1;
+    let mut greater_wall: usize = high_index - 1;
+    let mut i: usize = less_wall;
+
+    while i <= greater_wall {
+        if tracker.compare_by(arr, i, low_index, compare) == Ordering::Less {
+            tracker.swap(arr, i, less_wall);
+            less_wall += 1;
+        } else if tracker.compare_by(arr, i, high_index, compare) != Ordering::Less {
+            while i < greater_wall && tracker.compare_by(arr, greater_wall, high_index, compare) == Ordering::Greater {
+                greater_wall -= 1;
+            }
+            tracker.swap(arr, i, greater_wall);
+            greater_wall -= 1;
+
+            if tracker.compare_by(arr, i, low_index, compare) == Ordering::Less {
+                tracker.swap(arr, i, less_wall);
+                less_wall += 1;
+            }
+        }
+        i += 1;
+    }
+
+    less_wall -= 1;
+    greater_wall += 1;
+
+    tracker.swap(arr, low_index, less_wall);
+    tracker.swap(arr, high_index, greater_wall);
+
+    (less_wall, greater_wall)
+}
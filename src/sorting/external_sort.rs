@@ -0,0 +1,282 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::sorting::merge_sort::merge_sort;
+use crate::trees::heaps::priority_queue::MinPriorityQueue;
+
+const DEFAULT_MEMORY_BUDGET: usize = 64 * 1024 * 1024;
+const DEFAULT_FAN_IN: usize = 16;
+
+// Distinguishes run files created by concurrent sorts in the same process.
+static NEXT_RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Reads and writes the records sorted by `ExternalSort`.
+pub trait RecordCodec {
+    /// The decoded record. Records are sorted by their `Ord` implementation.
+    type Record: Ord + fmt::Debug;
+
+    /// Reads the next record, or returns `None` at the end of the input.
+    fn read_record<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<Self::Record>>;
+
+    /// Writes one record so that `read_record` can read it back.
+    fn write_record<W: Write>(&self, writer: &mut W, record: &Self::Record) -> io::Result<()>;
+
+    /// Estimates the memory held by a record, used to enforce the budget.
+    fn record_size(&self, record: &Self::Record) -> usize;
+}
+
+/// Newline-delimited UTF-8 text records. The newline is stripped on read and
+/// written back after every record, including the last.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineCodec;
+
+impl RecordCodec for LineCodec {
+    type Record = String;
+
+    fn read_record<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        if line.ends_with('\n') {
+            line.pop();
+        }
+        Ok(Some(line))
+    }
+
+    fn write_record<W: Write>(&self, writer: &mut W, record: &String) -> io::Result<()> {
+        writer.write_all(record.as_bytes())?;
+        writer.write_all(b"\n")
+    }
+
+    fn record_size(&self, record: &String) -> usize {
+        size_of::<String>() + record.capacity()
+    }
+}
+
+/// Binary records of exactly `width` bytes, compared byte by byte.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedWidthCodec {
+    width: usize,
+}
+
+impl FixedWidthCodec {
+    pub fn new(width: usize) -> Self {
+        assert!(width > 0, "record width must be positive");
+        FixedWidthCodec { width }
+    }
+}
+
+impl RecordCodec for FixedWidthCodec {
+    type Record = Vec<u8>;
+
+    fn read_record<R: BufRead>(&self, reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+        let mut record = vec![0; self.width];
+        let mut filled: usize = 0;
+        while filled < self.width {
+            match reader.read(&mut record[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        match filled {
+            0 => Ok(None),
+            _ if filled == self.width => Ok(Some(record)),
+            _ => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input ended in the middle of a record")),
+        }
+    }
+
+    fn write_record<W: Write>(&self, writer: &mut W, record: &Vec<u8>) -> io::Result<()> {
+        writer.write_all(record)
+    }
+
+    fn record_size(&self, record: &Vec<u8>) -> usize {
+        size_of::<Vec<u8>>() + record.capacity()
+    }
+}
+
+/// What an external sort did, for tests and capacity planning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalSortReport {
+    /// Number of records sorted.
+    pub records: usize,
+    /// Number of sorted runs spilled to temporary files. Zero when the whole
+    /// input fit in the memory budget.
+    pub initial_runs: usize,
+    /// Number of merge passes, including the final merge into the output.
+    pub merge_passes: usize,
+}
+
+/// Sorts inputs larger than memory.
+///
+/// Records are read into chunks that fit in the memory budget, each chunk is
+/// sorted with `merge_sort` and spilled to a temporary run file, and the runs
+/// are k-way merged through a `MinPriorityQueue`. When there are more runs
+/// than the fan-in allows, groups of runs are merged into longer runs first,
+/// so the sort never holds more than `fan_in` files open. The sort is stable.
+pub struct ExternalSort<C: RecordCodec> {
+    codec: C,
+    memory_budget: usize,
+    fan_in: usize,
+    temp_dir: PathBuf,
+}
+
+impl<C: RecordCodec> ExternalSort<C> {
+    /// Creates a sort with a 64 MiB memory budget, a fan-in of 16 and the
+    /// system temporary directory.
+    pub fn new(codec: C) -> Self {
+        ExternalSort {
+            codec,
+            memory_budget: DEFAULT_MEMORY_BUDGET,
+            fan_in: DEFAULT_FAN_IN,
+            temp_dir: std::env::temp_dir(),
+        }
+    }
+
+    /// Sets the approximate number of bytes of records held in memory at once.
+    pub fn memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = bytes;
+        self
+    }
+
+    /// Sets the maximum number of runs merged at once. Values below 2 are
+    /// treated as 2.
+    pub fn fan_in(mut self, runs: usize) -> Self {
+        self.fan_in = runs.max(2);
+        self
+    }
+
+    /// Sets the directory that holds the temporary run files.
+    pub fn temp_dir<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.temp_dir = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// Reads every record from `input` and writes them to `output` in order.
+    /// Temporary run files are removed before returning, even on error.
+    pub fn sort<R: Read, W: Write>(&self, input: R, output: W) -> io::Result<ExternalSortReport> {
+        let mut reader = BufReader::new(input);
+        let mut writer = BufWriter::new(output);
+        let mut report = ExternalSortReport { records: 0, initial_runs: 0, merge_passes: 0 };
+        let mut runs: Vec<RunFile> = Vec::new();
+
+        loop {
+            let (mut chunk, exhausted) = self.read_chunk(&mut reader)?;
+            report.records += chunk.len();
+            merge_sort(&mut chunk);
+
+            // The whole input fit in memory, so skip the temporary files.
+            if exhausted && runs.is_empty() {
+                for record in &chunk {
+                    self.codec.write_record(&mut writer, record)?;
+                }
+                writer.flush()?;
+                return Ok(report);
+            }
+
+            if !chunk.is_empty() {
+                let run = RunFile::create(&self.temp_dir)?;
+                let mut run_writer = BufWriter::new(File::create(&run.path)?);
+                for record in &chunk {
+                    self.codec.write_record(&mut run_writer, record)?;
+                }
+                run_writer.flush()?;
+                runs.push(run);
+            }
+
+            if exhausted {
+                break;
+            }
+        }
+        report.initial_runs = runs.len();
+
+        while runs.len() > self.fan_in {
+            let mut merged_runs: Vec<RunFile> = Vec::with_capacity(runs.len().div_ceil(self.fan_in));
+            for group in runs.chunks(self.fan_in) {
+                let run = RunFile::create(&self.temp_dir)?;
+                let mut run_writer = BufWriter::new(File::create(&run.path)?);
+                self.merge_runs(group, &mut run_writer)?;
+                run_writer.flush()?;
+                merged_runs.push(run);
+            }
+            runs = merged_runs;
+            report.merge_passes += 1;
+        }
+
+        self.merge_runs(&runs, &mut writer)?;
+        writer.flush()?;
+        report.merge_passes += 1;
+
+        Ok(report)
+    }
+
+    // Reads records until the next one would exceed the memory budget. Every
+    // chunk holds at least one record so oversized records still make
+    // progress. Also returns whether the input is exhausted.
+    fn read_chunk<R: BufRead>(&self, reader: &mut R) -> io::Result<(Vec<C::Record>, bool)> {
+        let mut chunk: Vec<C::Record> = Vec::new();
+        let mut chunk_size: usize = 0;
+
+        while let Some(record) = self.codec.read_record(reader)? {
+            chunk_size += self.codec.record_size(&record);
+            chunk.push(record);
+            if chunk_size >= self.memory_budget {
+                return Ok((chunk, false));
+            }
+        }
+
+        Ok((chunk, true))
+    }
+
+    // K-way merges the sorted runs into `output`. Queue priorities pair each
+    // record with the index of its run, so equal records leave in run order and
+    // the merge stays stable.
+    fn merge_runs<W: Write>(&self, runs: &[RunFile], output: &mut W) -> io::Result<()> {
+        let mut readers: Vec<BufReader<File>> = Vec::with_capacity(runs.len());
+        for run in runs {
+            readers.push(BufReader::new(File::open(&run.path)?));
+        }
+
+        let mut queue: MinPriorityQueue<usize, (C::Record, usize)> = MinPriorityQueue::with_capacity(runs.len());
+        for (run_index, reader) in readers.iter_mut().enumerate() {
+            if let Some(record) = self.codec.read_record(reader)? {
+                queue.push(run_index, (record, run_index));
+            }
+        }
+
+        while let Some(((record, _), run_index)) = queue.pop_with_priority() {
+            self.codec.write_record(output, &record)?;
+            if let Some(next) = self.codec.read_record(&mut readers[run_index])? {
+                queue.push(run_index, (next, run_index));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// A temporary run file that is deleted when dropped.
+struct RunFile {
+    path: PathBuf,
+}
+
+impl RunFile {
+    fn create(dir: &Path) -> io::Result<Self> {
+        let id = NEXT_RUN_ID.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!("rust_dsa_external_sort_{}_{}.run", process::id(), id));
+        File::create(&path)?;
+        Ok(RunFile { path })
+    }
+}
+
+impl Drop for RunFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,42 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn gnome_sort<T: PartialOrd>(arr: &mut [T]) {
+    gnome_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn gnome_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    gnome_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn gnome_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    gnome_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn gnome_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    gnome_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn gnome_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    gnome_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Walks forward while neighbours are in order and steps back after swapping
+// an out-of-order pair, so each element sinks to its place like in insertion
+// sort but without a nested loop.
+pub fn gnome_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let mut position: usize = 1;
+    while position < arr.len() {
+        if position == 0 || tracker.compare_by(arr, position - 1, position, &mut compare) != Ordering::Greater {
+            position += 1;
+        } else {
+            tracker.swap(arr, position - 1, position);
+            position -= 1;
+        }
+    }
+
+    tracker.stop_timer();
+}
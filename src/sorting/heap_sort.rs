@@ -1,34 +1,68 @@
-pub fn heap_sort<T: PartialOrd+Default+PartialEq>(arr: &mut [T]) {
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn heap_sort<T: PartialOrd>(arr: &mut [T]) {
+    heap_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn heap_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    heap_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn heap_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    heap_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn heap_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    heap_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn heap_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    heap_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn heap_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    heap_sort_untimed(arr, &mut compare, tracker);
+    tracker.stop_timer();
+}
+
+pub(crate) fn heap_sort_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], compare: &mut F, tracker: &mut K) {
     // Heapify array
+    tracker.enter_span("heapify");
     for i in (0..arr.len()/2).rev() {
-        max_heap_percolate(i, arr, arr.len())
+        max_heap_percolate(i, arr, arr.len(), compare, tracker)
     }
+    tracker.exit_span();
 
-    for i in (0..arr.len()).rev() {
-        arr.swap(0, i);
-        max_heap_percolate(0, arr, i);
+    tracker.enter_span("extract");
+    for i in (1..arr.len()).rev() {
+        tracker.swap(arr, 0, i);
+        max_heap_percolate(0, arr, i, compare, tracker);
     }
+    tracker.exit_span();
 }
 
-fn max_heap_percolate<T: PartialOrd+Default+PartialEq>(mut node_index: usize, heap_arr: &mut [T], heap_size: usize) {
+fn max_heap_percolate<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(mut node_index: usize, heap_arr: &mut [T], heap_size: usize, compare: &mut F, tracker: &mut K) {
     let mut child_index: usize = 2 * node_index + 1;
 
     while child_index < heap_size {
         let mut max_index: usize = node_index;
 
         for i in 0..2 {
-            if i + child_index < heap_size && heap_arr[max_index] < heap_arr[i + child_index] {
+            if i + child_index < heap_size && tracker.compare_by(heap_arr, max_index, i + child_index, compare) == Ordering::Less {
                 max_index = i + child_index;
             }
         }
 
-        if heap_arr[node_index].eq(&heap_arr[max_index]) {
+        if max_index == node_index {
             return;
         }
 
-        heap_arr.swap(node_index, max_index);
+        tracker.swap(heap_arr, node_index, max_index);
 
         node_index = max_index;
         child_index = 2 * node_index + 1;
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,45 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
 pub fn insertion_sort<T: PartialOrd>(arr: &mut [T]) {
-    for i in 1..arr.len() {
+    insertion_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn insertion_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    insertion_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn insertion_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    insertion_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn insertion_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    insertion_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn insertion_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    insertion_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn insertion_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    insertion_sort_untimed(arr, &mut compare, tracker);
+    tracker.stop_timer();
+}
+
+pub(crate) fn insertion_sort_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], compare: &mut F, tracker: &mut K) {
+    gapped_insertion_sort_untimed(arr, 1, compare, tracker);
+}
+
+// Insertion sort over the elements `gap` positions apart, leaving every
+// interleaved subsequence sorted. With a gap of 1 this is plain insertion sort.
+pub(crate) fn gapped_insertion_sort_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], gap: usize, compare: &mut F, tracker: &mut K) {
+    for i in gap..arr.len() {
         let mut j = i;
-        while j > 0 && arr[j] < arr[j - 1] {
-            arr.swap(j, j - 1);
-            j -= 1;
+        while j >= gap && tracker.compare_by(arr, j, j - gap, compare) == Ordering::Less {
+            tracker.swap(arr, j, j - gap);
+            j -= gap;
         }
     }
-}
\ No newline at end of file
+}
@@ -1,13 +1,15 @@
+use std::cmp::Ordering;
+use crate::sorting::insertion_sort::{insertion_sort_by_key_tracked, insertion_sort_by_tracked, insertion_sort_tracked};
 use crate::sorting::sort_tracker::SortTracker;
 
 pub fn insertion_sort<T: PartialOrd>(arr: &mut [T], tracker: &mut SortTracker) {
-    tracker.start_timer();
-    for i in 1..arr.len() {
-        let mut j = i;
-        while j > 0 && tracker.compare_lt(arr, j, j - 1) {
-            tracker.swap(arr, j, j - 1);
-            j -= 1;
-        }
-    }
-    tracker.stop_timer();
+    insertion_sort_tracked(arr, tracker);
+}
+
+pub fn insertion_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F, tracker: &mut SortTracker) {
+    insertion_sort_by_tracked(arr, compare, tracker);
+}
+
+pub fn insertion_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F, tracker: &mut SortTracker) {
+    insertion_sort_by_key_tracked(arr, key, tracker);
 }
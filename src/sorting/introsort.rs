@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::heap_sort::heap_sort_untimed;
+use crate::sorting::insertion_sort::insertion_sort_untimed;
+use crate::sorting::quick_sort_mod::partition;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+// Partitions at or below this size are finished with insertion sort.
+const INSERTION_SORT_CUTOFF: usize = 16;
+
+pub fn introsort<T: PartialOrd>(arr: &mut [T]) {
+    introsort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn introsort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    introsort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn introsort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    introsort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn introsort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    introsort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn introsort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    introsort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn introsort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    introsort_untimed(arr, &mut compare, tracker);
+    tracker.stop_timer();
+}
+
+pub(crate) fn introsort_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], compare: &mut F, tracker: &mut K) {
+    if !arr.is_empty() {
+        let depth_limit = 2 * arr.len().ilog2() as usize;
+        introsort_recursive(arr, 0, arr.len() - 1, depth_limit, compare, tracker);
+    }
+}
+
+fn introsort_recursive<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, depth_limit: usize, compare: &mut F, tracker: &mut K) {
+    if high_index <= low_index {
+        return;
+    }
+
+    if high_index - low_index < INSERTION_SORT_CUTOFF {
+        tracker.enter_span("insertion cutoff");
+        insertion_sort_untimed(&mut arr[low_index..=high_index], compare, &mut tracker.offset(low_index));
+        tracker.exit_span();
+    } else if depth_limit == 0 {
+        // Partitioning has gone quadratic on this range; heap sort bounds it to O(n log n).
+        tracker.enter_span("heap sort fallback");
+        heap_sort_untimed(&mut arr[low_index..=high_index], compare, &mut tracker.offset(low_index));
+        tracker.exit_span();
+    } else {
+        tracker.enter_span("partition");
+        let pivot_location = partition(arr, low_index, high_index, compare, tracker);
+        tracker.exit_span();
+
+        if pivot_location > low_index {
+            introsort_recursive(arr, low_index, pivot_location - 1, depth_limit - 1, compare, tracker);
+        }
+        introsort_recursive(arr, pivot_location + 1, high_index, depth_limit - 1, compare, tracker);
+    }
+}
@@ -1,47 +1,96 @@
-pub fn merge_sort<T: PartialOrd + Default>(arr: &mut [T]) {
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::sort_trace::SortEvent;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn merge_sort<T: PartialOrd>(arr: &mut [T]) {
+    merge_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn merge_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    merge_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn merge_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    merge_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn merge_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    merge_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn merge_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    merge_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn merge_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    merge_sort_untimed(arr, &mut compare, tracker);
+    tracker.stop_timer();
+}
+
+pub(crate) fn merge_sort_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], compare: &mut F, tracker: &mut K) {
     if !arr.is_empty() {
-        merge_sort_recursive(arr, 0, arr.len() - 1);
+        merge_sort_recursive(arr, 0, arr.len() - 1, compare, tracker);
     }
 }
 
-fn merge_sort_recursive<T: PartialOrd + Default>(arr: &mut [T], start_index: usize, end_index: usize) {
+fn merge_sort_recursive<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], start_index: usize, end_index: usize, compare: &mut F, tracker: &mut K) {
     if start_index < end_index {
         let mid: usize = (start_index + end_index) / 2;
 
-        merge_sort_recursive(arr, start_index, mid);
-        merge_sort_recursive(arr, mid + 1, end_index);
+        merge_sort_recursive(arr, start_index, mid, compare, tracker);
+        merge_sort_recursive(arr, mid + 1, end_index, compare, tracker);
 
-        merge(arr, start_index, mid, end_index);
+        tracker.enter_span("merge");
+        merge(arr, start_index, mid, end_index, compare, tracker);
+        tracker.exit_span();
     }
 }
 
-fn merge<T: PartialOrd + Default>(arr: &mut [T], left_first: usize, left_last: usize, right_last: usize) {
+pub(crate) fn merge<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], left_first: usize, left_last: usize, right_last: usize, compare: &mut F, tracker: &mut K) {
     let merged_size: usize = right_last - left_first + 1;
-    let mut merged_entries: Vec<T> = Vec::with_capacity(merged_size);
+    let mut merged_order: Vec<usize> = Vec::with_capacity(merged_size);
+    tracker.record_auxiliary_memory(merged_size * size_of::<usize>());
     let mut left_position: usize = left_first;
     let mut right_position: usize = left_last + 1;
 
     while left_position <= left_last && right_position <= right_last {
-        if arr[left_position] <= arr[right_position] {
-            merged_entries.push(std::mem::take(&mut arr[left_position]));
+        if tracker.compare_by(arr, left_position, right_position, compare) != Ordering::Greater {
+            merged_order.push(left_position - left_first);
             left_position += 1;
         } else {
-            merged_entries.push(std::mem::take(&mut arr[right_position]));
+            merged_order.push(right_position - left_first);
             right_position += 1;
         }
     }
 
-    while left_position <= left_last {
-        merged_entries.push(std::mem::take(&mut arr[left_position]));
-        left_position += 1;
-    }
+    merged_order.extend((left_position..=left_last).map(|position| position - left_first));
+    merged_order.extend((right_position..=right_last).map(|position| position - left_first));
 
-    while right_position <= right_last {
-        merged_entries.push(std::mem::take(&mut arr[right_position]));
-        right_position += 1;
-    }
+    apply_order(&mut arr[left_first..=right_last], &mut merged_order, &mut tracker.offset(left_first));
+    tracker.record_auxiliary_memory_freed(merged_size * size_of::<usize>());
+}
 
-    for merge_position in 0..merged_size {
-        arr[left_first + merge_position] = std::mem::take(&mut merged_entries[merge_position]);
+// Rearranges `arr` so that `arr[i]` receives the element previously at
+// `order[i]`. Each cycle of the permutation is walked with swaps, so elements
+// are moved without needing a placeholder value. `order` is consumed as the
+// visited marker. The swaps are reported as moves rather than counted swaps,
+// each reading and moving two elements.
+pub(crate) fn apply_order<T, K: Tracker>(arr: &mut [T], order: &mut [usize], tracker: &mut K) {
+    for start in 0..order.len() {
+        let mut current = start;
+        while order[current] != start {
+            let next = order[current];
+            tracker.record_read();
+            tracker.record_read();
+            tracker.record_move();
+            tracker.record_move();
+            tracker.record_event(SortEvent::Move { from: next, to: current });
+            arr.swap(current, next);
+            order[current] = current;
+            current = next;
+        }
+        order[current] = current;
     }
-}
\ No newline at end of file
+}
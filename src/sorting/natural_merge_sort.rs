@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::merge_sort::merge;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn natural_merge_sort<T: PartialOrd>(arr: &mut [T]) {
+    natural_merge_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn natural_merge_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    natural_merge_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn natural_merge_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    natural_merge_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn natural_merge_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    natural_merge_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn natural_merge_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    natural_merge_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn natural_merge_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    // Each run is stored as its exclusive end index; the next run starts where
+    // the previous one ends.
+    let mut run_ends: Vec<usize> = Vec::new();
+    let mut run_start: usize = 0;
+    while run_start < arr.len() {
+        run_start += count_run(arr, run_start, arr.len(), &mut compare, tracker);
+        run_ends.push(run_start);
+    }
+
+    while run_ends.len() > 1 {
+        let mut merged_ends: Vec<usize> = Vec::with_capacity(run_ends.len() / 2 + 1);
+        let mut left_first: usize = 0;
+
+        for pair in run_ends.chunks(2) {
+            if let [left_end, right_end] = *pair {
+                merge(arr, left_first, left_end - 1, right_end - 1, &mut compare, tracker);
+            }
+            left_first = pair[pair.len() - 1];
+            merged_ends.push(left_first);
+        }
+
+        run_ends = merged_ends;
+    }
+
+    tracker.stop_timer();
+}
+
+// Returns the length of the run starting at `run_start`. A strictly
+// descending run is reversed in place so every run comes back ascending;
+// requiring strictness keeps equal elements in their original order.
+pub(crate) fn count_run<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], run_start: usize, end: usize, compare: &mut F, tracker: &mut K) -> usize {
+    let mut run_end: usize = run_start + 1;
+    if run_end == end {
+        return 1;
+    }
+
+    if tracker.compare_by(arr, run_end, run_start, compare) == Ordering::Less {
+        run_end += 1;
+        while run_end < end && tracker.compare_by(arr, run_end, run_end - 1, compare) == Ordering::Less {
+            run_end += 1;
+        }
+
+        let (mut low, mut high) = (run_start, run_end - 1);
+        while low < high {
+            tracker.swap(arr, low, high);
+            low += 1;
+            high -= 1;
+        }
+    } else {
+        run_end += 1;
+        while run_end < end && tracker.compare_by(arr, run_end, run_end - 1, compare) != Ordering::Less {
+            run_end += 1;
+        }
+    }
+
+    run_end - run_start
+}
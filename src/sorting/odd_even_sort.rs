@@ -0,0 +1,46 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn odd_even_sort<T: PartialOrd>(arr: &mut [T]) {
+    odd_even_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn odd_even_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    odd_even_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn odd_even_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    odd_even_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn odd_even_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    odd_even_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn odd_even_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    odd_even_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Odd-even transposition sort: alternates between comparing the pairs starting
+// at odd indices and the pairs starting at even indices. The pairs within a
+// phase are independent, which is what makes it suited to parallel hardware.
+// Stops once an odd and an even phase both finish without swapping.
+pub fn odd_even_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let mut sorted = false;
+    while !sorted {
+        sorted = true;
+        for phase_start in [1, 0] {
+            for j in (phase_start..arr.len().saturating_sub(1)).step_by(2) {
+                if tracker.compare_by(arr, j, j + 1, &mut compare) == Ordering::Greater {
+                    tracker.swap(arr, j, j + 1);
+                    sorted = false;
+                }
+            }
+        }
+    }
+
+    tracker.stop_timer();
+}
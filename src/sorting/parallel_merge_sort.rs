@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+use std::thread;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::merge_sort::{merge, merge_sort_untimed};
+use crate::sorting::tracker::{NoopTracker, ParallelTracker};
+
+pub fn parallel_merge_sort<T: PartialOrd + Send>(arr: &mut [T], threads: usize, sequential_cutoff: usize) {
+    parallel_merge_sort_tracked(arr, threads, sequential_cutoff, &mut NoopTracker);
+}
+
+pub fn parallel_merge_sort_by<T: Send, F: Fn(&T, &T) -> Ordering + Sync>(arr: &mut [T], threads: usize, sequential_cutoff: usize, compare: F) {
+    parallel_merge_sort_by_tracked(arr, threads, sequential_cutoff, compare, &mut NoopTracker);
+}
+
+pub fn parallel_merge_sort_by_key<T: Send, B: PartialOrd, F: Fn(&T) -> B + Sync>(arr: &mut [T], threads: usize, sequential_cutoff: usize, key: F) {
+    parallel_merge_sort_by_key_tracked(arr, threads, sequential_cutoff, key, &mut NoopTracker);
+}
+
+pub fn parallel_merge_sort_tracked<T: PartialOrd + Send, K: ParallelTracker>(arr: &mut [T], threads: usize, sequential_cutoff: usize, tracker: &mut K) {
+    parallel_merge_sort_by_tracked(arr, threads, sequential_cutoff, partial_compare, tracker);
+}
+
+pub fn parallel_merge_sort_by_key_tracked<T: Send, B: PartialOrd, F: Fn(&T) -> B + Sync, K: ParallelTracker>(arr: &mut [T], threads: usize, sequential_cutoff: usize, key: F, tracker: &mut K) {
+    parallel_merge_sort_by_tracked(arr, threads, sequential_cutoff, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Splits the slice in half, sorting one half on a scoped worker thread and
+// the other on the current thread, until either the thread budget runs out or
+// the pieces drop to `sequential_cutoff` elements. Halves are split the same
+// way as `merge_sort`, so both perform exactly the same comparisons.
+pub fn parallel_merge_sort_by_tracked<T: Send, F: Fn(&T, &T) -> Ordering + Sync, K: ParallelTracker>(arr: &mut [T], threads: usize, sequential_cutoff: usize, compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    parallel_merge_sort_recursive(arr, 0, threads.max(1), sequential_cutoff, &compare, tracker);
+    tracker.stop_timer();
+}
+
+// `offset` is the position of `arr` in the slice being sorted, so traced
+// indices from every thread refer to the whole slice.
+fn parallel_merge_sort_recursive<T: Send, F: Fn(&T, &T) -> Ordering + Sync, K: ParallelTracker>(arr: &mut [T], offset: usize, threads: usize, sequential_cutoff: usize, compare: &F, tracker: &mut K) {
+    if threads == 1 || arr.len() <= sequential_cutoff.max(1) {
+        merge_sort_untimed(arr, &mut &*compare, &mut tracker.offset(offset));
+        return;
+    }
+
+    let mid: usize = arr.len().div_ceil(2);
+    let (left, right) = arr.split_at_mut(mid);
+    let worker_threads = threads / 2;
+
+    thread::scope(|scope| {
+        let mut worker_tracker = tracker.fork();
+        let worker = scope.spawn(move || {
+            parallel_merge_sort_recursive(left, offset, worker_threads, sequential_cutoff, compare, &mut worker_tracker);
+            worker_tracker
+        });
+
+        parallel_merge_sort_recursive(right, offset + mid, threads - worker_threads, sequential_cutoff, compare, tracker);
+
+        tracker.join(worker.join().expect("merge sort worker thread panicked"));
+    });
+
+    tracker.enter_span("merge");
+    merge(arr, 0, mid - 1, arr.len() - 1, &mut &*compare, &mut tracker.offset(offset));
+    tracker.exit_span();
+}
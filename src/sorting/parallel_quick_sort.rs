@@ -0,0 +1,64 @@
+use std::cmp::Ordering;
+use std::thread;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::quick_sort_mod::{partition, quick_sort_untimed};
+use crate::sorting::tracker::{NoopTracker, ParallelTracker};
+
+pub fn parallel_quick_sort<T: PartialOrd + Send>(arr: &mut [T], cutoff: usize, threads: usize, sequential_cutoff: usize) {
+    parallel_quick_sort_tracked(arr, cutoff, threads, sequential_cutoff, &mut NoopTracker);
+}
+
+pub fn parallel_quick_sort_by<T: Send, F: Fn(&T, &T) -> Ordering + Sync>(arr: &mut [T], cutoff: usize, threads: usize, sequential_cutoff: usize, compare: F) {
+    parallel_quick_sort_by_tracked(arr, cutoff, threads, sequential_cutoff, compare, &mut NoopTracker);
+}
+
+pub fn parallel_quick_sort_by_key<T: Send, B: PartialOrd, F: Fn(&T) -> B + Sync>(arr: &mut [T], cutoff: usize, threads: usize, sequential_cutoff: usize, key: F) {
+    parallel_quick_sort_by_key_tracked(arr, cutoff, threads, sequential_cutoff, key, &mut NoopTracker);
+}
+
+pub fn parallel_quick_sort_tracked<T: PartialOrd + Send, K: ParallelTracker>(arr: &mut [T], cutoff: usize, threads: usize, sequential_cutoff: usize, tracker: &mut K) {
+    parallel_quick_sort_by_tracked(arr, cutoff, threads, sequential_cutoff, partial_compare, tracker);
+}
+
+pub fn parallel_quick_sort_by_key_tracked<T: Send, B: PartialOrd, F: Fn(&T) -> B + Sync, K: ParallelTracker>(arr: &mut [T], cutoff: usize, threads: usize, sequential_cutoff: usize, key: F, tracker: &mut K) {
+    parallel_quick_sort_by_tracked(arr, cutoff, threads, sequential_cutoff, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Partitions on the current thread with `quick_sort_mod`'s median-of-three
+// partition, then sorts the side left of the pivot on a scoped worker thread
+// and the right side on the current thread. Pieces at or below
+// `sequential_cutoff` elements, or reached once the thread budget is spent,
+// fall back to the sequential `quick_sort_mod`.
+pub fn parallel_quick_sort_by_tracked<T: Send, F: Fn(&T, &T) -> Ordering + Sync, K: ParallelTracker>(arr: &mut [T], cutoff: usize, threads: usize, sequential_cutoff: usize, compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    parallel_quick_sort_recursive(arr, 0, cutoff, threads.max(1), sequential_cutoff, &compare, tracker);
+    tracker.stop_timer();
+}
+
+// `offset` is the position of `arr` in the slice being sorted, so traced
+// indices from every thread refer to the whole slice.
+fn parallel_quick_sort_recursive<T: Send, F: Fn(&T, &T) -> Ordering + Sync, K: ParallelTracker>(arr: &mut [T], offset: usize, cutoff: usize, threads: usize, sequential_cutoff: usize, compare: &F, tracker: &mut K) {
+    if threads == 1 || arr.len() <= sequential_cutoff.max(cutoff).max(1) {
+        quick_sort_untimed(arr, cutoff, &mut &*compare, &mut tracker.offset(offset));
+        return;
+    }
+
+    tracker.enter_span("partition");
+    let pivot_location = partition(arr, 0, arr.len() - 1, &mut &*compare, &mut tracker.offset(offset));
+    tracker.exit_span();
+    let (left, rest) = arr.split_at_mut(pivot_location);
+    let right = &mut rest[1..];
+    let worker_threads = threads / 2;
+
+    thread::scope(|scope| {
+        let mut worker_tracker = tracker.fork();
+        let worker = scope.spawn(move || {
+            parallel_quick_sort_recursive(left, offset, cutoff, worker_threads, sequential_cutoff, compare, &mut worker_tracker);
+            worker_tracker
+        });
+
+        parallel_quick_sort_recursive(right, offset + pivot_location + 1, cutoff, threads - worker_threads, sequential_cutoff, compare, tracker);
+
+        tracker.join(worker.join().expect("quick sort worker thread panicked"));
+    });
+}
@@ -1,31 +1,80 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
 pub fn quick_sort<T: PartialOrd>(arr: &mut [T]) {
-    if !arr.is_empty() {
-        quick_sort_recursive(arr, 0, arr.len() - 1)
-    }
+    quick_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn quick_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    quick_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn quick_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    quick_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn quick_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    quick_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn quick_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    quick_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
 }
 
-fn quick_sort_recursive<T: PartialOrd>(arr: &mut [T], low_index: usize, high_index: usize) {
-    if high_index <= low_index {
-        return;
+pub fn quick_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    if !arr.is_empty() {
+        sort_partitions(arr, 0, arr.len() - 1, &mut compare, tracker, &mut |arr, low_index, high_index, compare, tracker| {
+            Some(partition(arr, low_index, high_index, compare, tracker))
+        });
     }
+    tracker.stop_timer();
+}
 
-    let pivot_location: usize = partition(arr, low_index, high_index);
+// Sorts `arr[low_index..=high_index]` with `partition`, which places a pivot
+// and returns its position, or returns `None` once it has sorted the range
+// itself. The quick sort variants share this loop and differ only in how
+// they partition. It recurses into the smaller side and loops on the larger
+// one, so the stack depth stays O(log n) even when every partition is
+// lopsided.
+pub(crate) fn sort_partitions<T, F, K, P>(arr: &mut [T], mut low_index: usize, mut high_index: usize, compare: &mut F, tracker: &mut K, partition: &mut P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    K: Tracker,
+    P: FnMut(&mut [T], usize, usize, &mut F, &mut K) -> Option<usize>,
+{
+    while low_index < high_index {
+        let Some(pivot_location) = partition(arr, low_index, high_index, compare, tracker) else {
+            return;
+        };
 
-    quick_sort_recursive(arr, low_index, pivot_location);
-    quick_sort_recursive(arr, pivot_location + 1, high_index);
+        if pivot_location - low_index < high_index - pivot_location {
+            if pivot_location > low_index {
+                sort_partitions(arr, low_index, pivot_location - 1, compare, tracker, partition);
+            }
+            low_index = pivot_location + 1;
+        } else {
+            sort_partitions(arr, pivot_location + 1, high_index, compare, tracker, partition);
+            if pivot_location == low_index {
+                return;
+            }
+            high_index = pivot_location - 1;
+        }
+    }
 }
 
-fn partition<T: PartialOrd>(arr: &mut [T], low_index: usize, high_index: usize) -> usize {
+fn partition<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> usize {
     let pivot: usize = low_index;
     let mut leftwall: usize = low_index + 1;
 
     for i in (low_index+1)..=high_index {
-        if arr[i] < arr[pivot] {
-            arr.swap(i, leftwall);
+        if tracker.compare_by(arr, i, pivot, compare) == Ordering::Less {
+            tracker.swap(arr, i, leftwall);
             leftwall += 1;
         }
     }
 
-    arr.swap(pivot, leftwall - 1);
+    tracker.swap(arr, pivot, leftwall - 1);
     leftwall - 1
-}
\ No newline at end of file
+}
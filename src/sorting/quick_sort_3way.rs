@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn quick_sort<T: PartialOrd>(arr: &mut [T]) {
+    quick_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn quick_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    quick_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn quick_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    quick_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn quick_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    quick_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn quick_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    quick_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn quick_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    if !arr.is_empty() {
+        quick_sort_recursive(arr, 0, arr.len() - 1, &mut compare, tracker);
+    }
+    tracker.stop_timer();
+}
+
+fn quick_sort_recursive<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut low_index: usize, mut high_index: usize, compare: &mut F, tracker: &mut K) {
+    while low_index < high_index {
+        let (equal_first, equal_last) = partition(arr, low_index, high_index, compare, tracker);
+
+        // Elements equal to the pivot are already in place. Recurse into the
+        // smaller outer side and loop on the larger one.
+        if equal_first - low_index < high_index - equal_last {
+            if equal_first > low_index {
+                quick_sort_recursive(arr, low_index, equal_first - 1, compare, tracker);
+            }
+            low_index = equal_last + 1;
+        } else {
+            if equal_last < high_index {
+                quick_sort_recursive(arr, equal_last + 1, high_index, compare, tracker);
+            }
+            if equal_first == low_index {
+                return;
+            }
+            high_index = equal_first - 1;
+        }
+    }
+}
+
+// Dijkstra's Dutch national flag partition. On return, `arr[low_index..equal_first]`
+// is less than the pivot, `arr[equal_first..=equal_last]` is equal to it and
+// `arr[equal_last + 1..=high_index]` is greater.
+fn partition<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> (usize, usize) {
+    let midpoint: usize = low_index + (high_index - low_index) / 2;
+
+    tracker.swap(arr, midpoint, low_index);
+
+    partition_around_first(arr, low_index, high_index, compare, tracker)
+}
+
+// Three-way partition around the pivot already placed at `arr[low_index]`.
+pub(crate) fn partition_around_first<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> (usize, usize) {
+    // `arr[less_wall]` always holds a copy of the pivot value.
+    let mut less_wall: usize = low_index;
+    let mut greater_wall: usize = high_index;
+    let mut i: usize = low_index + 1;
+
+    while i <= greater_wall {
+        match tracker.compare_by(arr, i, less_wall, compare) {
+            Ordering::Less => {
+                tracker.swap(arr, i, less_wall);
+                less_wall += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                tracker.swap(arr, i, greater_wall);
+                greater_wall -= 1;
+            }
+            Ordering::Equal => {
+                i += 1;
+            }
+        }
+    }
+
+    (less_wall, greater_wall)
+}
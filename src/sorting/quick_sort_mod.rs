@@ -1,62 +1,85 @@
-use crate::sorting::insertion_sort::insertion_sort;
+use std::cmp::Ordering;
+use crate::sorting::insertion_sort::insertion_sort_untimed;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::quick_sort::sort_partitions;
+use crate::sorting::tracker::{NoopTracker, Tracker};
 
 pub fn quick_sort<T: PartialOrd>(arr: &mut [T], cutoff: usize) {
-    if arr.is_empty() {
-        return;
-    }
+    quick_sort_tracked(arr, cutoff, &mut NoopTracker);
+}
 
-    quick_sort_recursive(arr, 0, arr.len() - 1, cutoff);
+pub fn quick_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], cutoff: usize, compare: F) {
+    quick_sort_by_tracked(arr, cutoff, compare, &mut NoopTracker);
 }
 
-fn quick_sort_recursive<T: PartialOrd>(arr: &mut [T], low_index: usize, high_index: usize, cutoff: usize) {
-    if high_index <= low_index {
-        return;
-    }
+pub fn quick_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], cutoff: usize, key: F) {
+    quick_sort_by_key_tracked(arr, cutoff, key, &mut NoopTracker);
+}
 
-    if high_index - low_index + 1 <= cutoff {
-        insertion_sort(&mut arr[low_index..=high_index]);
-    } else {
-        let pivot_location = partition(arr, low_index, high_index);
+pub fn quick_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], cutoff: usize, tracker: &mut K) {
+    quick_sort_by_tracked(arr, cutoff, partial_compare, tracker);
+}
 
-        if pivot_location > 0 {
-            quick_sort_recursive(arr, low_index, pivot_location - 1, cutoff);
-        }
-        quick_sort_recursive(arr, pivot_location + 1, high_index, cutoff);
+pub fn quick_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], cutoff: usize, mut key: F, tracker: &mut K) {
+    quick_sort_by_tracked(arr, cutoff, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn quick_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], cutoff: usize, mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+    quick_sort_untimed(arr, cutoff, &mut compare, tracker);
+    tracker.stop_timer();
+}
+
+pub(crate) fn quick_sort_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], cutoff: usize, compare: &mut F, tracker: &mut K) {
+    if !arr.is_empty() {
+        sort_partitions(arr, 0, arr.len() - 1, compare, tracker, &mut |arr, low_index, high_index, compare, tracker| {
+            if high_index - low_index < cutoff {
+                tracker.enter_span("insertion cutoff");
+                insertion_sort_untimed(&mut arr[low_index..=high_index], compare, &mut tracker.offset(low_index));
+                tracker.exit_span();
+                return None;
+            }
+
+            tracker.enter_span("partition");
+            let pivot_location = partition(arr, low_index, high_index, compare, tracker);
+            tracker.exit_span();
+            Some(pivot_location)
+        });
     }
 }
 
-fn partition<T: PartialOrd>(arr: &mut [T], low_index: usize, high_index: usize) -> usize {
-    let midpoint = median_of_three(arr, low_index, high_index);
+pub(crate) fn partition<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> usize {
+    let midpoint = median_of_three(arr, low_index, high_index, compare, tracker);
 
-    arr.swap(midpoint, low_index);
+    tracker.swap(arr, midpoint, low_index);
 
     let pivot = low_index;
     let mut leftwall = low_index + 1;
 
-    for i in leftwall..=high_index {
-        if arr[i] < arr[pivot] {
-            arr.swap(i, leftwall);
+    for i in (low_index+1)..=high_index {
+        if tracker.compare_by(arr, i, pivot, compare) == Ordering::Less {
+            tracker.swap(arr, i, leftwall);
             leftwall += 1;
         }
     }
 
-    arr.swap(pivot, leftwall - 1);
+    tracker.swap(arr, pivot, leftwall - 1);
     leftwall - 1
 }
 
-fn median_of_three<T: PartialOrd>(arr: &mut [T], low_index: usize, high_index: usize) -> usize {
+fn median_of_three<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> usize {
     let middle = (low_index + high_index) / 2;
 
-    if arr[low_index] > arr[middle] {
-        arr.swap(low_index, middle);
+    if tracker.compare_by(arr, low_index, middle, compare) == Ordering::Greater {
+        tracker.swap(arr, low_index, middle);
     }
 
-    if arr[low_index] > arr[high_index] {
-        arr.swap(low_index, high_index);
+    if tracker.compare_by(arr, low_index, high_index, compare) == Ordering::Greater {
+        tracker.swap(arr, low_index, high_index);
     }
 
-    if arr[middle] > arr[high_index] {
-        arr.swap(middle, high_index);
+    if tracker.compare_by(arr, middle, high_index, compare) == Ordering::Greater {
+        tracker.swap(arr, middle, high_index);
     }
 
     middle
@@ -0,0 +1,45 @@
+/// A fixed-width integer key that the non-comparison sorts can split into
+/// bytes.
+///
+/// `to_ordered_bits` maps the key to an unsigned value with the same ordering,
+/// flipping the sign bit of signed types so that negative keys sort first.
+pub trait RadixKey: Copy {
+    /// Width of the key in bytes, which is the number of LSD radix passes.
+    const BYTES: usize;
+
+    /// Returns an unsigned value that orders the same way as `self`.
+    fn to_ordered_bits(self) -> u64;
+}
+
+macro_rules! impl_unsigned_radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = size_of::<$t>();
+
+                #[inline]
+                fn to_ordered_bits(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_radix_key {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = size_of::<$t>();
+
+                #[inline]
+                fn to_ordered_bits(self) -> u64 {
+                    ((self as $u) ^ (1 << (<$u>::BITS - 1))) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_radix_key!(u8, u16, u32, u64, usize);
+impl_signed_radix_key!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
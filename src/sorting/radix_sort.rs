@@ -0,0 +1,166 @@
+use crate::sorting::merge_sort::apply_order;
+use crate::sorting::radix_key::RadixKey;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+// One bucket per byte value, plus one for keys that have already ended.
+const MSD_BUCKETS: usize = 257;
+
+pub fn lsd_radix_sort<T: RadixKey>(arr: &mut [T]) {
+    lsd_radix_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn lsd_radix_sort_by_key<T, B: RadixKey, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    lsd_radix_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn lsd_radix_sort_tracked<T: RadixKey, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    lsd_radix_sort_by_key_tracked(arr, |&value| value, tracker);
+}
+
+// Distributes positions by one byte of the key per pass, starting from the
+// least significant byte. Every pass is stable, so after the last pass the
+// positions are ordered by the whole key. Passes where every key has the same
+// byte are skipped. The passes ping-pong between the two halves of one index
+// buffer, and the elements are moved only once.
+pub fn lsd_radix_sort_by_key_tracked<T, B: RadixKey, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    if arr.len() > 1 {
+        let keys: Vec<u64> = arr.iter().map(|value| key(value).to_ordered_bits()).collect();
+        keys.iter().for_each(|_| tracker.record_read());
+        lsd_radix_sort_keys_untimed(arr, &keys, B::BYTES, tracker);
+    }
+
+    tracker.stop_timer();
+}
+
+// Sorts `arr` by `keys`, already read from its elements as ordered bits of
+// which only the lowest `bytes` bytes can differ.
+pub(crate) fn lsd_radix_sort_keys_untimed<T, K: Tracker>(arr: &mut [T], keys: &[u64], bytes: usize, tracker: &mut K) {
+    let size = arr.len();
+    if size > 1 {
+        let mut buffer: Vec<usize> = Vec::with_capacity(2 * size);
+        tracker.record_auxiliary_memory(size * size_of::<u64>() + 2 * size * size_of::<usize>());
+        buffer.extend(0..size);
+        buffer.resize(2 * size, 0);
+
+        let (mut source, mut target) = buffer.split_at_mut(size);
+
+        for byte in 0..bytes {
+            let digit = |position: usize| ((keys[position] >> (8 * byte)) & 0xFF) as usize;
+
+            let mut counts = [0usize; 256 + 1];
+            for &position in source.iter() {
+                counts[digit(position) + 1] += 1;
+            }
+            if counts.contains(&size) {
+                continue;
+            }
+            for i in 1..counts.len() {
+                counts[i] += counts[i - 1];
+            }
+
+            tracker.record_pass();
+            for &position in source.iter() {
+                let bucket = digit(position);
+                target[counts[bucket]] = position;
+                counts[bucket] += 1;
+                tracker.record_bucket_write();
+            }
+
+            std::mem::swap(&mut source, &mut target);
+        }
+
+        apply_order(arr, source, tracker);
+        tracker.record_auxiliary_memory_freed(size * size_of::<u64>() + 2 * size * size_of::<usize>());
+    }
+}
+
+pub fn msd_radix_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    msd_radix_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn msd_radix_sort_by_key<T, B: AsRef<[u8]>, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    msd_radix_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn msd_radix_sort_tracked<T: AsRef<[u8]>, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    tracker.start_timer();
+    if arr.len() > 1 {
+        let mut order = msd_radix_order(arr, tracker);
+        apply_order(arr, &mut order, tracker);
+        tracker.record_auxiliary_memory_freed(2 * arr.len() * size_of::<usize>());
+    }
+    tracker.stop_timer();
+}
+
+pub fn msd_radix_sort_by_key_tracked<T, B: AsRef<[u8]>, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], key: F, tracker: &mut K) {
+    tracker.start_timer();
+    if arr.len() > 1 {
+        let keys: Vec<B> = arr.iter().map(key).collect();
+        keys.iter().for_each(|_| tracker.record_read());
+        tracker.record_auxiliary_memory(keys.len() * size_of::<B>());
+        let mut order = msd_radix_order(&keys, tracker);
+        apply_order(arr, &mut order, tracker);
+        tracker.record_auxiliary_memory_freed(keys.len() * size_of::<B>() + 2 * keys.len() * size_of::<usize>());
+    }
+    tracker.stop_timer();
+}
+
+// Returns the positions of `keys` in lexicographic order by distributing them
+// on their first byte, then on the second byte within each bucket, and so on.
+// Keys that end are placed ahead of every longer key sharing their prefix.
+// Buckets are kept on an explicit work stack, so long common prefixes cannot
+// overflow the call stack.
+fn msd_radix_order<B: AsRef<[u8]>, K: Tracker>(keys: &[B], tracker: &mut K) -> Vec<usize> {
+    let size = keys.len();
+    let mut order: Vec<usize> = (0..size).collect();
+    let mut scratch: Vec<usize> = vec![0; size];
+    tracker.record_auxiliary_memory(2 * size * size_of::<usize>());
+
+    let digit = |position: usize, depth: usize| keys[position].as_ref().get(depth).map_or(0, |&byte| byte as usize + 1);
+    let mut pending: Vec<(usize, usize, usize)> = vec![(0, size, 0)];
+
+    while let Some((start, end, depth)) = pending.pop() {
+        let mut counts = [0usize; MSD_BUCKETS + 1];
+        for &position in &order[start..end] {
+            counts[digit(position, depth) + 1] += 1;
+        }
+
+        // Every key in the bucket has ended, so they are all equal.
+        if counts[1] == end - start {
+            continue;
+        }
+        // Every key shares this byte, so move straight on to the next one.
+        if counts.contains(&(end - start)) {
+            pending.push((start, end, depth + 1));
+            continue;
+        }
+
+        for i in 1..counts.len() {
+            counts[i] += counts[i - 1];
+        }
+
+        tracker.record_pass();
+        for &position in &order[start..end] {
+            let bucket = digit(position, depth);
+            scratch[start + counts[bucket]] = position;
+            counts[bucket] += 1;
+            tracker.record_read();
+            tracker.record_bucket_write();
+        }
+        order[start..end].copy_from_slice(&scratch[start..end]);
+
+        // `counts[bucket]` now holds the end of each bucket. Bucket 0 holds
+        // keys that have ended and needs no further work.
+        for bucket in 1..MSD_BUCKETS {
+            let bucket_start = start + counts[bucket - 1];
+            let bucket_end = start + counts[bucket];
+            if bucket_end - bucket_start > 1 {
+                pending.push((bucket_start, bucket_end, depth + 1));
+            }
+        }
+    }
+
+    order
+}
@@ -0,0 +1,444 @@
+use crate::sorting::bottom_up_merge_sort::{bottom_up_merge_sort_by_key, bottom_up_merge_sort_by_key_tracked};
+use crate::sorting::bubble_sort::{bubble_sort_by_key, bubble_sort_by_key_tracked};
+use crate::sorting::bucket_sort::{bucket_sort_by_key, bucket_sort_by_key_tracked};
+use crate::sorting::cocktail_shaker_sort::{cocktail_shaker_sort_by_key, cocktail_shaker_sort_by_key_tracked};
+use crate::sorting::counting_sort::{counting_sort_by_key, counting_sort_by_key_tracked};
+use crate::sorting::cycle_sort::{cycle_sort_by_key, cycle_sort_by_key_tracked};
+use crate::sorting::dual_pivot_quick_sort::{dual_pivot_quick_sort_by_key, dual_pivot_quick_sort_by_key_tracked};
+use crate::sorting::gnome_sort::{gnome_sort_by_key, gnome_sort_by_key_tracked};
+use crate::sorting::heap_sort::{heap_sort_by_key, heap_sort_by_key_tracked};
+use crate::sorting::insertion_sort::{insertion_sort_by_key, insertion_sort_by_key_tracked};
+use crate::sorting::introsort::{introsort_by_key, introsort_by_key_tracked};
+use crate::sorting::merge_sort::{merge_sort_by_key, merge_sort_by_key_tracked};
+use crate::sorting::natural_merge_sort::{natural_merge_sort_by_key, natural_merge_sort_by_key_tracked};
+use crate::sorting::odd_even_sort::{odd_even_sort_by_key, odd_even_sort_by_key_tracked};
+use crate::sorting::parallel_merge_sort::{parallel_merge_sort_by_key, parallel_merge_sort_by_key_tracked};
+use crate::sorting::parallel_quick_sort::{parallel_quick_sort_by_key, parallel_quick_sort_by_key_tracked};
+use crate::sorting::radix_sort::{lsd_radix_sort_by_key, lsd_radix_sort_by_key_tracked, msd_radix_sort_by_key, msd_radix_sort_by_key_tracked};
+use crate::sorting::selection_sort::{selection_sort_by_key, selection_sort_by_key_tracked};
+use crate::sorting::shell_sort::{shell_sort_by_key, shell_sort_by_key_tracked, GapSequence};
+use crate::sorting::sort_tracker::SortTracker;
+use crate::sorting::tim_sort::{tim_sort_by_key, tim_sort_by_key_tracked};
+use crate::sorting::{quick_sort, quick_sort_3way, quick_sort_mid, quick_sort_mod};
+
+// Parameters for the sorts that take them. The cutoff matches the benchmarks.
+const QUICK_SORT_CUTOFF: usize = 10;
+const PARALLEL_THREADS: usize = 4;
+const PARALLEL_SEQUENTIAL_CUTOFF: usize = 128;
+
+/// An element the registered sorts can order, by its `i32` key.
+///
+/// Every sort in the registry, including the integer-only counting, radix and
+/// bucket sorts, goes through its `_by_key` entry point, so wrapping a key
+/// with a payload, such as its original position, lets callers check
+/// stability. Keys are `Clone` because some sorts, such as the bottom-up
+/// merge sort, merge through a buffer of elements.
+pub trait SortKey: Clone + Send {
+    fn sort_key(&self) -> i32;
+}
+
+impl SortKey for i32 {
+    fn sort_key(&self) -> i32 {
+        *self
+    }
+}
+
+/// A sorting algorithm with its entry points and properties.
+///
+/// Complexities are in terms of the number of elements `n`, the range of keys
+/// `k` and the key width in bytes `w`.
+pub struct SortAlgorithm<T> {
+    pub name: &'static str,
+    pub sort: fn(&mut [T]),
+    pub sort_tracked: fn(&mut [T], &mut SortTracker),
+    /// Whether elements with equal keys keep their relative order.
+    pub stable: bool,
+    /// Whether the sort uses no more than `O(log n)` auxiliary memory.
+    pub in_place: bool,
+    /// Whether the sort does less work on input that is already partly sorted.
+    pub adaptive: bool,
+    pub best_case: &'static str,
+    pub average_case: &'static str,
+    pub worst_case: &'static str,
+    pub auxiliary_space: &'static str,
+}
+
+// Derived impls would require `T: Clone`, although only function pointers to
+// `T` are stored.
+impl<T> Clone for SortAlgorithm<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SortAlgorithm<T> {}
+
+// The MSD radix sort takes byte-string keys, so the key's sign bit is flipped
+// to make its big-endian bytes order the same way as the key.
+fn radix_bytes<T: SortKey>(value: &T) -> [u8; 4] {
+    ((value.sort_key() as u32) ^ 0x8000_0000).to_be_bytes()
+}
+
+fn float_key<T: SortKey>(value: &T) -> f64 {
+    value.sort_key() as f64
+}
+
+/// Returns every sorting algorithm the crate provides, grouped by family in
+/// the same order as the modules in `sorting`.
+pub fn sort_algorithms<T: SortKey>() -> Vec<SortAlgorithm<T>> {
+    vec![
+        SortAlgorithm {
+            name: "Selection Sort",
+            sort: |arr| selection_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| selection_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n^2)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Bubble Sort",
+            sort: |arr| bubble_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| bubble_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Cocktail Shaker Sort",
+            sort: |arr| cocktail_shaker_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| cocktail_shaker_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Gnome Sort",
+            sort: |arr| gnome_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| gnome_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Odd-Even Sort",
+            sort: |arr| odd_even_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| odd_even_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Cycle Sort",
+            sort: |arr| cycle_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| cycle_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n^2)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Insertion Sort",
+            sort: |arr| insertion_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| insertion_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Shell Sort (Shell)",
+            sort: |arr| shell_sort_by_key(arr, GapSequence::Shell, T::sort_key),
+            sort_tracked: |arr, tracker| shell_sort_by_key_tracked(arr, GapSequence::Shell, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n log n)",
+            average_case: "O(n^2)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Shell Sort (Knuth)",
+            sort: |arr| shell_sort_by_key(arr, GapSequence::Knuth, T::sort_key),
+            sort_tracked: |arr, tracker| shell_sort_by_key_tracked(arr, GapSequence::Knuth, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n log n)",
+            average_case: "O(n^(3/2))",
+            worst_case: "O(n^(3/2))",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Shell Sort (Sedgewick)",
+            sort: |arr| shell_sort_by_key(arr, GapSequence::Sedgewick, T::sort_key),
+            sort_tracked: |arr, tracker| shell_sort_by_key_tracked(arr, GapSequence::Sedgewick, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n log n)",
+            average_case: "O(n^(7/6))",
+            worst_case: "O(n^(4/3))",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Shell Sort (Ciura)",
+            sort: |arr| shell_sort_by_key(arr, GapSequence::Ciura, T::sort_key),
+            sort_tracked: |arr, tracker| shell_sort_by_key_tracked(arr, GapSequence::Ciura, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n log n)",
+            average_case: "unknown",
+            worst_case: "unknown",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Shell Sort (Tokuda)",
+            sort: |arr| shell_sort_by_key(arr, GapSequence::Tokuda, T::sort_key),
+            sort_tracked: |arr, tracker| shell_sort_by_key_tracked(arr, GapSequence::Tokuda, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: true,
+            best_case: "O(n log n)",
+            average_case: "unknown",
+            worst_case: "unknown",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Merge Sort",
+            sort: |arr| merge_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| merge_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "Bottom-Up Merge Sort",
+            sort: |arr| bottom_up_merge_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| bottom_up_merge_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "Parallel Merge Sort",
+            sort: |arr| parallel_merge_sort_by_key(arr, PARALLEL_THREADS, PARALLEL_SEQUENTIAL_CUTOFF, T::sort_key),
+            sort_tracked: |arr, tracker| parallel_merge_sort_by_key_tracked(arr, PARALLEL_THREADS, PARALLEL_SEQUENTIAL_CUTOFF, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "Natural Merge Sort",
+            sort: |arr| natural_merge_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| natural_merge_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "Tim Sort",
+            sort: |arr| tim_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| tim_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: true,
+            best_case: "O(n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "Heap Sort",
+            sort: |arr| heap_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| heap_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(1)",
+        },
+        SortAlgorithm {
+            name: "Quick Sort",
+            sort: |arr| quick_sort::quick_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| quick_sort::quick_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Quick Sort (mid)",
+            sort: |arr| quick_sort_mid::quick_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| quick_sort_mid::quick_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Quick Sort (modified)",
+            sort: |arr| quick_sort_mod::quick_sort_by_key(arr, QUICK_SORT_CUTOFF, T::sort_key),
+            sort_tracked: |arr, tracker| quick_sort_mod::quick_sort_by_key_tracked(arr, QUICK_SORT_CUTOFF, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Quick Sort (3-way)",
+            sort: |arr| quick_sort_3way::quick_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| quick_sort_3way::quick_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Dual-Pivot Quick Sort",
+            sort: |arr| dual_pivot_quick_sort_by_key(arr, QUICK_SORT_CUTOFF, T::sort_key),
+            sort_tracked: |arr, tracker| dual_pivot_quick_sort_by_key_tracked(arr, QUICK_SORT_CUTOFF, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Parallel Quick Sort",
+            sort: |arr| parallel_quick_sort_by_key(arr, QUICK_SORT_CUTOFF, PARALLEL_THREADS, PARALLEL_SEQUENTIAL_CUTOFF, T::sort_key),
+            sort_tracked: |arr, tracker| parallel_quick_sort_by_key_tracked(arr, QUICK_SORT_CUTOFF, PARALLEL_THREADS, PARALLEL_SEQUENTIAL_CUTOFF, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Introsort",
+            sort: |arr| introsort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| introsort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: false,
+            in_place: true,
+            adaptive: false,
+            best_case: "O(n log n)",
+            average_case: "O(n log n)",
+            worst_case: "O(n log n)",
+            auxiliary_space: "O(log n)",
+        },
+        SortAlgorithm {
+            name: "Counting Sort",
+            sort: |arr| counting_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| counting_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n + k)",
+            average_case: "O(n + k)",
+            worst_case: "O(n + k)",
+            auxiliary_space: "O(n + k)",
+        },
+        SortAlgorithm {
+            name: "LSD Radix Sort",
+            sort: |arr| lsd_radix_sort_by_key(arr, T::sort_key),
+            sort_tracked: |arr, tracker| lsd_radix_sort_by_key_tracked(arr, T::sort_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n)",
+            average_case: "O(w * n)",
+            worst_case: "O(w * n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "MSD Radix Sort",
+            sort: |arr| msd_radix_sort_by_key(arr, radix_bytes),
+            sort_tracked: |arr, tracker| msd_radix_sort_by_key_tracked(arr, radix_bytes, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n)",
+            average_case: "O(w * n)",
+            worst_case: "O(w * n)",
+            auxiliary_space: "O(n)",
+        },
+        SortAlgorithm {
+            name: "Bucket Sort",
+            sort: |arr| bucket_sort_by_key(arr, float_key),
+            sort_tracked: |arr, tracker| bucket_sort_by_key_tracked(arr, float_key, tracker),
+            stable: true,
+            in_place: false,
+            adaptive: false,
+            best_case: "O(n)",
+            average_case: "O(n)",
+            worst_case: "O(n^2)",
+            auxiliary_space: "O(n)",
+        },
+    ]
+}
+
+/// Returns the registered algorithm called `name`, ignoring case.
+pub fn find_sort_algorithm<T: SortKey>(name: &str) -> Option<SortAlgorithm<T>> {
+    sort_algorithms().into_iter().find(|algorithm| algorithm.name.eq_ignore_ascii_case(name))
+}
@@ -0,0 +1,209 @@
+use std::cmp::Ordering;
+use std::fmt;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::counted::{ComparisonCounter, Counted};
+use crate::sorting::insertion_sort::insertion_sort_untimed;
+use crate::sorting::introsort::introsort_untimed;
+use crate::sorting::quick_sort_3way::partition_around_first;
+use crate::sorting::quick_sort_mod::partition;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+use crate::trees::heaps::heap::Heap;
+use crate::trees::heaps::max_heap::MaxHeap;
+use crate::trees::heaps::min_heap::MinHeap;
+
+// Ranges this short are insertion sorted instead of partitioned.
+const INSERTION_SORT_CUTOFF: usize = 16;
+
+// Median-of-medians works on groups of this many elements.
+const GROUP_SIZE: usize = 5;
+
+pub fn select_nth<T: PartialOrd>(arr: &mut [T], nth: usize) {
+    select_nth_tracked(arr, nth, &mut NoopTracker);
+}
+
+pub fn select_nth_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], nth: usize, compare: F) {
+    select_nth_by_tracked(arr, nth, compare, &mut NoopTracker);
+}
+
+pub fn select_nth_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], nth: usize, key: F) {
+    select_nth_by_key_tracked(arr, nth, key, &mut NoopTracker);
+}
+
+pub fn select_nth_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], nth: usize, tracker: &mut K) {
+    select_nth_by_tracked(arr, nth, partial_compare, tracker);
+}
+
+pub fn select_nth_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], nth: usize, mut key: F, tracker: &mut K) {
+    select_nth_by_tracked(arr, nth, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Reorders `arr` so that `arr[nth]` holds the element that would be there if
+// `arr` were sorted, with nothing greater before it and nothing less after it.
+//
+// Quickselect partitions with `quick_sort_mod`'s median-of-three partition and
+// keeps only the side holding `nth`. After 2 * log2(n) partitions it switches
+// to median-of-medians pivots with a three-way partition, which bounds the
+// worst case to O(n) even for adversarial inputs and many duplicates.
+pub fn select_nth_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], nth: usize, mut compare: F, tracker: &mut K) {
+    assert!(nth < arr.len(), "select_nth index {} is out of bounds for length {}", nth, arr.len());
+
+    tracker.start_timer();
+    select_nth_untimed(arr, nth, &mut compare, tracker);
+    tracker.stop_timer();
+}
+
+fn select_nth_untimed<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], nth: usize, compare: &mut F, tracker: &mut K) {
+    let depth_limit = 2 * arr.len().ilog2() as usize;
+    select_range(arr, 0, arr.len() - 1, nth, depth_limit, compare, tracker);
+}
+
+fn select_range<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut low_index: usize, mut high_index: usize, nth: usize, mut depth_limit: usize, compare: &mut F, tracker: &mut K) {
+    while high_index > low_index {
+        if high_index - low_index < INSERTION_SORT_CUTOFF {
+            insertion_sort_untimed(&mut arr[low_index..=high_index], compare, &mut tracker.offset(low_index));
+            return;
+        }
+
+        let (equal_first, equal_last) = if depth_limit > 0 {
+            depth_limit -= 1;
+            let pivot_location = partition(arr, low_index, high_index, compare, tracker);
+            (pivot_location, pivot_location)
+        } else {
+            let pivot = median_of_medians(arr, low_index, high_index, compare, tracker);
+            tracker.swap(arr, pivot, low_index);
+            partition_around_first(arr, low_index, high_index, compare, tracker)
+        };
+
+        if nth < equal_first {
+            high_index = equal_first - 1;
+        } else if nth > equal_last {
+            low_index = equal_last + 1;
+        } else {
+            return;
+        }
+    }
+}
+
+// Returns the index of an element guaranteed to rank between the 30th and
+// 70th percentile of `arr[low_index..=high_index]`. Each group of five is
+// insertion sorted and its median moved to the front of the range, then the
+// median of those medians is selected recursively.
+fn median_of_medians<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], low_index: usize, high_index: usize, compare: &mut F, tracker: &mut K) -> usize {
+    let mut medians_end = low_index;
+    for group_start in (low_index..=high_index).step_by(GROUP_SIZE) {
+        let group_end = (group_start + GROUP_SIZE - 1).min(high_index);
+        insertion_sort_untimed(&mut arr[group_start..=group_end], compare, &mut tracker.offset(group_start));
+        tracker.swap(arr, group_start + (group_end - group_start) / 2, medians_end);
+        medians_end += 1;
+    }
+
+    let median = low_index + (medians_end - 1 - low_index) / 2;
+    select_range(arr, low_index, medians_end - 1, median, 0, compare, tracker);
+    median
+}
+
+pub fn partial_sort<T: PartialOrd>(arr: &mut [T], k: usize) {
+    partial_sort_tracked(arr, k, &mut NoopTracker);
+}
+
+pub fn partial_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], k: usize, compare: F) {
+    partial_sort_by_tracked(arr, k, compare, &mut NoopTracker);
+}
+
+pub fn partial_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], k: usize, key: F) {
+    partial_sort_by_key_tracked(arr, k, key, &mut NoopTracker);
+}
+
+pub fn partial_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], k: usize, tracker: &mut K) {
+    partial_sort_by_tracked(arr, k, partial_compare, tracker);
+}
+
+pub fn partial_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], k: usize, mut key: F, tracker: &mut K) {
+    partial_sort_by_tracked(arr, k, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Moves the `k` smallest elements to the front of `arr` in sorted order,
+// leaving the rest in unspecified order. Selects the k-th smallest element to
+// split off the prefix, then introsorts only the prefix: O(n + k log k).
+pub fn partial_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], k: usize, mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let k = k.min(arr.len());
+    if k > 0 {
+        if k < arr.len() {
+            select_nth_untimed(arr, k - 1, &mut compare, tracker);
+        }
+        introsort_untimed(&mut arr[..k], &mut compare, tracker);
+    }
+
+    tracker.stop_timer();
+}
+
+pub fn top_k<T: Ord + fmt::Debug, I: IntoIterator<Item = T>>(iter: I, k: usize) -> Vec<T> {
+    top_k_tracked(iter, k, &mut NoopTracker)
+}
+
+pub fn bottom_k<T: Ord + fmt::Debug, I: IntoIterator<Item = T>>(iter: I, k: usize) -> Vec<T> {
+    bottom_k_tracked(iter, k, &mut NoopTracker)
+}
+
+// Returns the `k` largest items, largest first, in one pass over `iter`. A
+// `MinHeap` holds the largest `k` items seen so far, so each new item only has
+// to beat its root, using O(k) memory and O(n log k) comparisons.
+pub fn top_k_tracked<T: Ord + fmt::Debug, I: IntoIterator<Item = T>, K: Tracker>(iter: I, k: usize, tracker: &mut K) -> Vec<T> {
+    tracker.start_timer();
+
+    let counter = ComparisonCounter::new();
+    let mut heap: MinHeap<Counted<T>> = MinHeap::with_capacity(k);
+    tracker.record_auxiliary_memory(k * size_of::<Counted<T>>());
+    if k > 0 {
+        for value in iter {
+            tracker.record_read();
+            let item = counter.wrap(value);
+            if heap.len() < k {
+                heap.push(item);
+            } else if heap.peek().is_some_and(|smallest| item > *smallest) {
+                heap.pop();
+                heap.push(item);
+            }
+        }
+    }
+
+    let mut largest: Vec<T> = Vec::from(heap).into_iter().map(Counted::into_inner).collect();
+    largest.reverse();
+    counter.record_into(tracker);
+    tracker.record_auxiliary_memory_freed(k * size_of::<Counted<T>>());
+
+    tracker.stop_timer();
+    largest
+}
+
+// Returns the `k` smallest items, smallest first, keeping them in a bounded
+// `MaxHeap` like `top_k_tracked`.
+pub fn bottom_k_tracked<T: Ord + fmt::Debug, I: IntoIterator<Item = T>, K: Tracker>(iter: I, k: usize, tracker: &mut K) -> Vec<T> {
+    tracker.start_timer();
+
+    let counter = ComparisonCounter::new();
+    let mut heap: MaxHeap<Counted<T>> = MaxHeap::with_capacity(k);
+    tracker.record_auxiliary_memory(k * size_of::<Counted<T>>());
+    if k > 0 {
+        for value in iter {
+            tracker.record_read();
+            let item = counter.wrap(value);
+            if heap.len() < k {
+                heap.push(item);
+            } else if heap.peek().is_some_and(|largest| item < *largest) {
+                heap.pop();
+                heap.push(item);
+            }
+        }
+    }
+
+    let mut smallest: Vec<T> = Vec::from(heap).into_iter().map(Counted::into_inner).collect();
+    smallest.reverse();
+    counter.record_into(tracker);
+    tracker.record_auxiliary_memory_freed(k * size_of::<Counted<T>>());
+
+    tracker.stop_timer();
+    smallest
+}
@@ -0,0 +1,44 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+pub fn selection_sort<T: PartialOrd>(arr: &mut [T]) {
+    selection_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn selection_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    selection_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn selection_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    selection_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn selection_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    selection_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn selection_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    selection_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Repeatedly selects the smallest remaining element and swaps it to the front
+// of the unsorted part. Always makes n(n-1)/2 comparisons but at most n - 1
+// swaps. Not stable.
+pub fn selection_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    for i in 0..arr.len() {
+        let mut min_index = i;
+        for j in (i + 1)..arr.len() {
+            if tracker.compare_by(arr, j, min_index, &mut compare) == Ordering::Less {
+                min_index = j;
+            }
+        }
+        if min_index != i {
+            tracker.swap(arr, i, min_index);
+        }
+    }
+
+    tracker.stop_timer();
+}
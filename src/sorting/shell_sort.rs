@@ -0,0 +1,82 @@
+use std::cmp::Ordering;
+use std::iter::{once, successors};
+use crate::sorting::compare::partial_compare;
+use crate::sorting::insertion_sort::gapped_insertion_sort_untimed;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+// Ciura's empirically derived gaps. Longer inputs extend the sequence by
+// multiplying the last gap by 2.25.
+const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+/// The gap sequence used by `shell_sort`, from the largest gap below the
+/// input length down to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapSequence {
+    /// Shell's original `n/2, n/4, ..., 1`.
+    Shell,
+    /// Knuth's `(3^k - 1) / 2`: 1, 4, 13, 40, 121, ...
+    Knuth,
+    /// Sedgewick's `4^k + 3 * 2^(k-1) + 1`: 1, 8, 23, 77, 281, ...
+    Sedgewick,
+    /// Ciura's 1, 4, 10, 23, 57, 132, 301, 701, 1750, extended by a factor of 2.25.
+    Ciura,
+    /// Tokuda's `ceil((9^k - 4^k) / (5 * 4^(k-1)))`: 1, 4, 9, 20, 46, 103, ...
+    Tokuda,
+}
+
+impl GapSequence {
+    /// Returns the gaps to use for an input of length `len`, largest first.
+    pub fn gaps(self, len: usize) -> Vec<usize> {
+        match self {
+            GapSequence::Shell => successors(Some(len / 2), |&gap| Some(gap / 2)).take_while(|&gap| gap > 0).collect(),
+            GapSequence::Knuth => gaps_below(successors(Some(1), |&gap| Some(3 * gap + 1)), len),
+            GapSequence::Sedgewick => gaps_below(once(1).chain((1..).map(|k| 4usize.pow(k) + 3 * 2usize.pow(k - 1) + 1)), len),
+            GapSequence::Ciura => {
+                let extension = successors(Some(CIURA_GAPS[CIURA_GAPS.len() - 1] as f64 * 2.25), |&gap| Some(gap * 2.25));
+                gaps_below(CIURA_GAPS.iter().copied().chain(extension.map(|gap| gap as usize)), len)
+            }
+            GapSequence::Tokuda => gaps_below(successors(Some(1.0f64), |&gap| Some(2.25 * gap + 1.0)).map(|gap| gap.ceil() as usize), len),
+        }
+    }
+}
+
+// Collects the gaps of an increasing sequence that are below `len`, largest
+// first.
+fn gaps_below<I: Iterator<Item = usize>>(sequence: I, len: usize) -> Vec<usize> {
+    let mut gaps: Vec<usize> = sequence.take_while(|&gap| gap < len).collect();
+    gaps.reverse();
+    gaps
+}
+
+pub fn shell_sort<T: PartialOrd>(arr: &mut [T], sequence: GapSequence) {
+    shell_sort_tracked(arr, sequence, &mut NoopTracker);
+}
+
+pub fn shell_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], sequence: GapSequence, compare: F) {
+    shell_sort_by_tracked(arr, sequence, compare, &mut NoopTracker);
+}
+
+pub fn shell_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], sequence: GapSequence, key: F) {
+    shell_sort_by_key_tracked(arr, sequence, key, &mut NoopTracker);
+}
+
+pub fn shell_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], sequence: GapSequence, tracker: &mut K) {
+    shell_sort_by_tracked(arr, sequence, partial_compare, tracker);
+}
+
+pub fn shell_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], sequence: GapSequence, mut key: F, tracker: &mut K) {
+    shell_sort_by_tracked(arr, sequence, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+// Runs the insertion sort inner loop once per gap, largest first. The final
+// gap is always 1, so the last pass is a plain insertion sort over an almost
+// sorted slice.
+pub fn shell_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], sequence: GapSequence, mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    for gap in sequence.gaps(arr.len()) {
+        gapped_insertion_sort_untimed(arr, gap, &mut compare, tracker);
+    }
+
+    tracker.stop_timer();
+}
@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+use std::slice;
+
+/// One step taken by a traced sort.
+///
+/// Indices refer to the whole slice passed to the sort, even when the step was
+/// taken by a nested sort working on part of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortEvent {
+    /// `array[index1]` was compared with `array[index2]`, giving `result`.
+    /// Incomparable values are reported as `Equal`.
+    Compare { index1: usize, index2: usize, result: Ordering },
+    /// `array[index1]` and `array[index2]` were swapped.
+    Swap { index1: usize, index2: usize },
+    /// The element at `from` was moved to its final position `to` while a
+    /// merge or distribution sort applied the order it had computed. The
+    /// element previously at `to` takes its place at `from`. Moves are not
+    /// counted as swaps.
+    Move { from: usize, to: usize },
+    /// The element at `index` was written to its final position. Only sorts
+    /// that minimise writes, such as cycle sort, report this.
+    Write { index: usize },
+}
+
+impl SortEvent {
+    /// Applies the event's effect to `array`. Compares and writes leave it
+    /// unchanged. Every event is its own inverse, so applying it again undoes it.
+    pub fn apply<T>(&self, array: &mut [T]) {
+        match *self {
+            SortEvent::Swap { index1, index2 } => array.swap(index1, index2),
+            SortEvent::Move { from, to } => array.swap(from, to),
+            SortEvent::Compare { .. } | SortEvent::Write { .. } => {}
+        }
+    }
+
+    pub(crate) fn shifted(self, offset: usize) -> Self {
+        match self {
+            SortEvent::Compare { index1, index2, result } => SortEvent::Compare { index1: index1 + offset, index2: index2 + offset, result },
+            SortEvent::Swap { index1, index2 } => SortEvent::Swap { index1: index1 + offset, index2: index2 + offset },
+            SortEvent::Move { from, to } => SortEvent::Move { from: from + offset, to: to + offset },
+            SortEvent::Write { index } => SortEvent::Write { index: index + offset },
+        }
+    }
+}
+
+/// The ordered log of events recorded by a `SortTracker` in trace mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SortTrace {
+    events: Vec<SortEvent>,
+}
+
+impl SortTrace {
+    pub fn new() -> Self {
+        SortTrace { events: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn events(&self) -> &[SortEvent] {
+        &self.events
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, SortEvent> {
+        self.events.iter()
+    }
+
+    /// Rebuilds the array as it was after the first `step` events, given the
+    /// array the sort started from. Steps past the end give the final state.
+    pub fn replay<T: Clone>(&self, initial: &[T], step: usize) -> Vec<T> {
+        let mut replay = self.replayer(initial);
+        replay.seek(step);
+        replay.state
+    }
+
+    /// Returns a cursor that steps through the trace from `initial` in either
+    /// direction.
+    pub fn replayer<T: Clone>(&self, initial: &[T]) -> Replay<'_, T> {
+        Replay {
+            events: &self.events,
+            state: initial.to_vec(),
+            position: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, event: SortEvent) {
+        self.events.push(event);
+    }
+
+    pub(crate) fn append(&mut self, other: SortTrace) {
+        self.events.extend(other.events);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+impl<'a> IntoIterator for &'a SortTrace {
+    type Item = &'a SortEvent;
+    type IntoIter = slice::Iter<'a, SortEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A position in a `SortTrace` together with the array state at that position.
+pub struct Replay<'a, T> {
+    events: &'a [SortEvent],
+    state: Vec<T>,
+    position: usize,
+}
+
+impl<T> Replay<'_, T> {
+    /// The array after the events before `position` have been applied.
+    pub fn state(&self) -> &[T] {
+        &self.state
+    }
+
+    /// The number of events applied so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Applies the next event and returns it, or `None` at the end.
+    pub fn step_forward(&mut self) -> Option<SortEvent> {
+        let event = *self.events.get(self.position)?;
+        event.apply(&mut self.state);
+        self.position += 1;
+        Some(event)
+    }
+
+    /// Undoes the last applied event and returns it, or `None` at the start.
+    pub fn step_back(&mut self) -> Option<SortEvent> {
+        self.position = self.position.checked_sub(1)?;
+        let event = self.events[self.position];
+        event.apply(&mut self.state);
+        Some(event)
+    }
+
+    /// Moves forwards or backwards until `step` events have been applied.
+    pub fn seek(&mut self, step: usize) {
+        let step = step.min(self.events.len());
+        while self.position < step {
+            self.step_forward();
+        }
+        while self.position > step {
+            self.step_back();
+        }
+    }
+}
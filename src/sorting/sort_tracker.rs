@@ -1,12 +1,31 @@
-use std::cmp::PartialOrd;
 use std::time::{Instant, Duration};
+use crate::sorting::sort_trace::{SortEvent, SortTrace};
+use crate::sorting::timing_span::{SpanCounts, SpanTree};
+use crate::sorting::tracker::ParallelTracker;
+pub use crate::sorting::tracker::Tracker;
 
 pub struct SortTracker {
     comparison_count: usize,
     swap_count: usize,
+    read_count: usize,
+    move_count: usize,
+    auxiliary_memory: usize,
+    current_auxiliary_memory: usize,
+    peak_auxiliary_memory: usize,
+    write_count: usize,
+    pass_count: usize,
+    bucket_write_count: usize,
     start_time: Option<Instant>,
     duration: Duration,
-    lock_timer: bool,
+    timer_depth: usize,
+    spans: Option<SpanTree>,
+    trace: Option<SortTrace>,
+}
+
+impl Default for SortTracker {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SortTracker {
@@ -14,26 +33,61 @@ impl SortTracker {
         SortTracker {
             comparison_count: 0,
             swap_count: 0,
+            read_count: 0,
+            move_count: 0,
+            auxiliary_memory: 0,
+            current_auxiliary_memory: 0,
+            peak_auxiliary_memory: 0,
+            write_count: 0,
+            pass_count: 0,
+            bucket_write_count: 0,
             start_time: None,
             duration: Duration::new(0, 0),
-            lock_timer: false,
+            timer_depth: 0,
+            spans: None,
+            trace: None,
+        }
+    }
+
+    /// Creates a tracker in trace mode, which also records every compare,
+    /// swap, move and write as an ordered event log.
+    pub fn with_trace() -> Self {
+        SortTracker {
+            trace: Some(SortTrace::new()),
+            ..SortTracker::new()
+        }
+    }
+
+    /// Creates a tracker that also records the per-phase timing spans opened
+    /// through `enter_span`. Spans are off by default because every span reads
+    /// the clock twice.
+    pub fn with_spans() -> Self {
+        SortTracker {
+            spans: Some(SpanTree::new()),
+            ..SortTracker::new()
         }
     }
 
     pub fn reset(&mut self) {
         self.comparison_count = 0;
         self.swap_count = 0;
+        self.read_count = 0;
+        self.move_count = 0;
+        self.auxiliary_memory = 0;
+        self.current_auxiliary_memory = 0;
+        self.peak_auxiliary_memory = 0;
+        self.write_count = 0;
+        self.pass_count = 0;
+        self.bucket_write_count = 0;
         self.start_time = None;
         self.duration = Duration::new(0, 0);
-        self.lock_timer = false;
-    }
-
-    pub fn lock_timer(&mut self) {
-        self.lock_timer = true;
-    }
-
-    pub fn unlock_timer(&mut self) {
-        self.lock_timer = false;
+        self.timer_depth = 0;
+        if let Some(spans) = &mut self.spans {
+            spans.clear();
+        }
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
     }
 
     pub fn get_comparison_count(&self) -> usize {
@@ -44,40 +98,82 @@ impl SortTracker {
         self.swap_count
     }
 
+    // The helpers below forward to `Tracker`, so that callers can keep using
+    // them without importing the trait.
+
     pub fn compare_gt<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
-        self.comparison_count += 1;
-        array[index1] > array[index2]
+        Tracker::compare_gt(self, array, index1, index2)
     }
 
     pub fn compare_gte<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
-        self.comparison_count += 1;
-        array[index1] >= array[index2]
+        Tracker::compare_gte(self, array, index1, index2)
     }
 
     pub fn compare_lt<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
-        self.comparison_count += 1;
-        array[index1] < array[index2]
+        Tracker::compare_lt(self, array, index1, index2)
     }
 
     pub fn compare_lte<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
-        self.comparison_count += 1;
-        array[index1] <= array[index2]
+        Tracker::compare_lte(self, array, index1, index2)
     }
 
     pub fn swap<T>(&mut self, array: &mut [T], index1: usize, index2: usize) {
-        self.swap_count += 1;
-        array.swap(index1, index2);
+        Tracker::swap(self, array, index1, index2);
     }
 
+    /// Returns the number of elements, or keys derived from them, read from
+    /// the array. Every comparison and swap reads two elements.
+    pub fn get_read_count(&self) -> usize {
+        self.read_count
+    }
+
+    /// Returns the number of times an element changed position. Every swap
+    /// moves two elements, and sorts that compute an order first, such as
+    /// merge sort, report the moves made while applying it.
+    pub fn get_move_count(&self) -> usize {
+        self.move_count
+    }
+
+    /// Returns the total bytes of auxiliary memory allocated over the run.
+    pub fn get_auxiliary_memory(&self) -> usize {
+        self.auxiliary_memory
+    }
+
+    /// Returns the most auxiliary memory held at any one time.
+    pub fn get_peak_auxiliary_memory(&self) -> usize {
+        self.peak_auxiliary_memory
+    }
+
+    pub fn get_write_count(&self) -> usize {
+        self.write_count
+    }
+
+    /// Returns the recorded events, or `None` when not in trace mode.
+    pub fn get_trace(&self) -> Option<&SortTrace> {
+        self.trace.as_ref()
+    }
+
+    pub fn get_pass_count(&self) -> usize {
+        self.pass_count
+    }
+
+    pub fn get_bucket_write_count(&self) -> usize {
+        self.bucket_write_count
+    }
+
+    /// Starts timing a run. A call made while the timer is already running,
+    /// such as by a sort nested in a timed benchmark, only nests inside it.
     pub fn start_timer(&mut self) {
-        if self.lock_timer {
-            return;
+        if self.timer_depth == 0 {
+            self.start_time = Some(Instant::now());
         }
-        self.start_time = Some(Instant::now());
+        self.timer_depth += 1;
     }
 
+    /// Stops timing a run once every nested `start_timer` has been matched.
     pub fn stop_timer(&mut self) {
-        if self.lock_timer {
+        self.timer_depth = self.timer_depth.saturating_sub(1);
+        if self.timer_depth > 0 {
             return;
         }
         if let Some(start) = self.start_time {
@@ -88,4 +184,125 @@ impl SortTracker {
     pub fn get_duration(&self) -> Duration {
         self.duration
     }
-}
\ No newline at end of file
+
+    /// Opens a named timing span. See `Tracker::enter_span`. Does nothing
+    /// unless the tracker was created with `with_spans`.
+    pub fn enter_span(&mut self, name: &'static str) {
+        let counts = self.span_counts();
+        if let Some(spans) = &mut self.spans {
+            spans.enter(name, counts);
+        }
+    }
+
+    /// Closes the most recently opened timing span.
+    pub fn exit_span(&mut self) {
+        let counts = self.span_counts();
+        if let Some(spans) = &mut self.spans {
+            spans.exit(counts);
+        }
+    }
+
+    fn span_counts(&self) -> SpanCounts {
+        SpanCounts { comparisons: self.comparison_count, swaps: self.swap_count }
+    }
+
+    /// Returns the per-phase timings and counts recorded through `enter_span`,
+    /// or `None` when spans are not enabled.
+    pub fn get_spans(&self) -> Option<&SpanTree> {
+        self.spans.as_ref()
+    }
+}
+
+impl Tracker for SortTracker {
+    fn record_comparison(&mut self) {
+        self.comparison_count += 1;
+    }
+
+    fn record_swap(&mut self) {
+        self.swap_count += 1;
+    }
+
+    fn record_read(&mut self) {
+        self.read_count += 1;
+    }
+
+    fn record_move(&mut self) {
+        self.move_count += 1;
+    }
+
+    fn record_auxiliary_memory(&mut self, bytes: usize) {
+        self.auxiliary_memory += bytes;
+        self.current_auxiliary_memory += bytes;
+        self.peak_auxiliary_memory = self.peak_auxiliary_memory.max(self.current_auxiliary_memory);
+    }
+
+    fn record_auxiliary_memory_freed(&mut self, bytes: usize) {
+        self.current_auxiliary_memory = self.current_auxiliary_memory.saturating_sub(bytes);
+    }
+
+    fn record_write(&mut self, index: usize) {
+        self.write_count += 1;
+        self.record_event(SortEvent::Write { index });
+    }
+
+    fn record_pass(&mut self) {
+        self.pass_count += 1;
+    }
+
+    fn record_bucket_write(&mut self) {
+        self.bucket_write_count += 1;
+    }
+
+    fn record_event(&mut self, event: SortEvent) {
+        if let Some(trace) = &mut self.trace {
+            trace.push(event);
+        }
+    }
+
+    fn enter_span(&mut self, name: &'static str) {
+        SortTracker::enter_span(self, name);
+    }
+
+    fn exit_span(&mut self) {
+        SortTracker::exit_span(self);
+    }
+
+    fn start_timer(&mut self) {
+        SortTracker::start_timer(self);
+    }
+
+    fn stop_timer(&mut self) {
+        SortTracker::stop_timer(self);
+    }
+}
+
+impl ParallelTracker for SortTracker {
+    fn fork(&self) -> Self {
+        SortTracker {
+            spans: self.spans.as_ref().map(|_| SpanTree::new()),
+            trace: self.trace.as_ref().map(|_| SortTrace::new()),
+            ..SortTracker::new()
+        }
+    }
+
+    // A worker's peak is counted on top of what the parent held when it was
+    // joined, so workers are treated as if they had run one after another.
+    fn join(&mut self, worker: Self) {
+        self.comparison_count += worker.comparison_count;
+        self.swap_count += worker.swap_count;
+        self.read_count += worker.read_count;
+        self.move_count += worker.move_count;
+        self.auxiliary_memory += worker.auxiliary_memory;
+        self.peak_auxiliary_memory = self.peak_auxiliary_memory.max(self.current_auxiliary_memory + worker.peak_auxiliary_memory);
+        self.current_auxiliary_memory += worker.current_auxiliary_memory;
+        self.write_count += worker.write_count;
+        self.pass_count += worker.pass_count;
+        self.bucket_write_count += worker.bucket_write_count;
+        if let (Some(spans), Some(worker_spans)) = (&mut self.spans, worker.spans) {
+            spans.append(worker_spans);
+        }
+        if let (Some(trace), Some(worker_trace)) = (&mut self.trace, worker.trace) {
+            trace.append(worker_trace);
+        }
+    }
+}
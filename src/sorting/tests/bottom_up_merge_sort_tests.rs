@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod bottom_up_merge_sort_tests {
+    use std::num::NonZeroU32;
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::bottom_up_merge_sort::{bottom_up_merge_sort, bottom_up_merge_sort_by, bottom_up_merge_sort_by_key, bottom_up_merge_sort_tracked};
+    use crate::sorting::merge_sort::merge_sort_tracked;
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_ascending_array() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr, vec![10, 20, 30, 40, 50, 60, 70, 80, 90]);
+    }
+
+    #[test]
+    fn test_sort_descending_array() {
+        let mut arr = vec![99, 88, 77, 66, 55, 44, 33, 22, 11];
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        bottom_up_merge_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_non_default_values() {
+        let mut arr: Vec<NonZeroU32> = [33, 18, 78, 64, 45, 32, 70, 11, 27].iter().map(|&n| NonZeroU32::new(n).unwrap()).collect();
+        bottom_up_merge_sort(&mut arr);
+        let sorted: Vec<u32> = arr.iter().map(|n| n.get()).collect();
+        assert_eq!(sorted, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for size in [2, 3, 5, 1000, 1023, 1025] {
+            for mut arr in [generate_random_vec(size), generate_sorted_vec(size), generate_reverse_sorted_vec(size), generate_few_unique_vec(size, 4)] {
+                let mut expected = arr.clone();
+                expected.sort();
+                bottom_up_merge_sort(&mut arr);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        bottom_up_merge_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut arr: Vec<(i32, usize)> = generate_random_vec(2000).into_iter().map(|value| value % 20).zip(0..).collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|&(group, _)| group);
+        bottom_up_merge_sort_by_key(&mut arr, |&(group, _)| group);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_allocates_a_single_buffer() {
+        let mut arr = generate_random_vec(1000);
+        let mut tracker = SortTracker::new();
+        bottom_up_merge_sort_tracked(&mut arr, &mut tracker);
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(tracker.get_auxiliary_memory(), 1000 * size_of::<i32>());
+        assert_eq!(tracker.get_peak_auxiliary_memory(), 1000 * size_of::<i32>());
+    }
+
+    #[test]
+    fn test_every_pass_moves_every_element() {
+        // 10 passes, which leave the result in `arr`.
+        let mut arr = generate_sorted_vec(1000);
+        let mut tracker = SortTracker::new();
+        bottom_up_merge_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(tracker.get_move_count(), 10 * 1000);
+        assert_eq!(tracker.get_swap_count(), 0);
+
+        // 3 passes, which leave the result in the buffer to be moved back.
+        let mut arr = vec![8, 7, 6, 5, 4, 3, 2, 1];
+        let mut tracker = SortTracker::new();
+        bottom_up_merge_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(tracker.get_move_count(), 4 * 8);
+        assert_eq!(tracker.get_comparison_count(), 12);
+    }
+
+    #[test]
+    fn test_uses_less_auxiliary_memory_than_merge_sort() {
+        let input = generate_random_vec(1000);
+
+        let mut arr = input.clone();
+        let mut tracker = SortTracker::new();
+        bottom_up_merge_sort_tracked(&mut arr, &mut tracker);
+
+        let mut arr = input;
+        let mut merge_tracker = SortTracker::new();
+        merge_sort_tracked(&mut arr, &mut merge_tracker);
+
+        assert!(tracker.get_auxiliary_memory() < merge_tracker.get_auxiliary_memory());
+    }
+
+    #[test]
+    fn test_tracked_empty_array_allocates_nothing() {
+        let mut arr: [i32; 0] = [];
+        let mut tracker = SortTracker::new();
+        bottom_up_merge_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(tracker.get_comparison_count(), 0);
+        assert_eq!(tracker.get_auxiliary_memory(), 0);
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod bucket_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::bucket_sort::{bucket_sort, bucket_sort_by_key, bucket_sort_tracked};
+    use crate::util::data_generation::generate_random_vec;
+
+    #[test]
+    fn test_sort_unsorted_floats() {
+        let mut arr = vec![0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+        bucket_sort(&mut arr);
+        assert_eq!(arr, vec![0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+    }
+
+    #[test]
+    fn test_sort_negative_and_f32_values() {
+        let mut arr: Vec<f32> = vec![2.5, -1.0, 0.0, -3.75, 1.25];
+        bucket_sort(&mut arr);
+        assert_eq!(arr, vec![-3.75, -1.0, 0.0, 1.25, 2.5]);
+    }
+
+    #[test]
+    fn test_sort_equal_and_infinite_values() {
+        let mut arr = vec![1.5; 10];
+        bucket_sort(&mut arr);
+        assert_eq!(arr, vec![1.5; 10]);
+
+        let mut arr = vec![3.0, f64::INFINITY, -2.0, f64::NEG_INFINITY, 0.5];
+        bucket_sort(&mut arr);
+        assert_eq!(arr, vec![f64::NEG_INFINITY, -2.0, 0.5, 3.0, f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1.0];
+        bucket_sort(&mut arr);
+        assert_eq!(arr, [1.0]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [f64; 0] = [];
+        bucket_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        let mut arr: Vec<f64> = generate_random_vec(5000).iter().map(|&value| value as f64 / 100_000.0).collect();
+        let mut expected = arr.clone();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        bucket_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 0.3), ("apple", 0.1), ("date", 0.4), ("cherry", 0.2)];
+        bucket_sort_by_key(&mut arr, |&(_, weight)| weight);
+        assert_eq!(arr, vec![("apple", 0.1), ("cherry", 0.2), ("banana", 0.3), ("date", 0.4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts_for_uniform_input() {
+        let mut arr: Vec<f64> = (0..1000).rev().map(|value| value as f64).collect();
+        let mut tracker = SortTracker::new();
+        bucket_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, (0..1000).map(|value| value as f64).collect::<Vec<f64>>());
+        assert_eq!(tracker.get_pass_count(), 1);
+        assert_eq!(tracker.get_bucket_write_count(), 1000);
+        assert!(tracker.get_comparison_count() < 1000);
+    }
+
+    #[test]
+    fn test_infinite_range_falls_back_to_merge_sort() {
+        let mut arr: Vec<f64> = generate_random_vec(2000).iter().map(|&value| value as f64).collect();
+        arr.push(f64::INFINITY);
+        arr.push(f64::NAN);
+        arr.push(f64::NEG_INFINITY);
+        let mut tracker = SortTracker::new();
+        bucket_sort_tracked(&mut arr, &mut tracker);
+
+        assert_eq!(arr[0], f64::NEG_INFINITY);
+        assert_eq!(arr[2001], f64::INFINITY);
+        assert!(arr[2002].is_nan());
+        assert!(arr[..2002].windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(tracker.get_pass_count(), 0);
+        assert!(tracker.get_comparison_count() < 2003 * 12);
+    }
+
+    #[test]
+    fn test_overflowing_range_falls_back_to_merge_sort() {
+        let mut arr = vec![f64::MAX, 1.0, -f64::MAX, 0.0, f64::MAX];
+        let mut tracker = SortTracker::new();
+        bucket_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![-f64::MAX, 0.0, 1.0, f64::MAX, f64::MAX]);
+        assert_eq!(tracker.get_pass_count(), 0);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod cocktail_shaker_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::cocktail_shaker_sort::{cocktail_shaker_sort, cocktail_shaker_sort_by, cocktail_shaker_sort_by_key, cocktail_shaker_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+    use crate::sorting::bubble_sort::bubble_sort_tracked;
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        cocktail_shaker_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        cocktail_shaker_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        cocktail_shaker_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        cocktail_shaker_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(500), generate_sorted_vec(500), generate_reverse_sorted_vec(500), generate_few_unique_vec(500, 4)] {
+            let mut expected = arr.clone();
+            expected.sort();
+            cocktail_shaker_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        cocktail_shaker_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        cocktail_shaker_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        let mut tracker = SortTracker::new();
+        cocktail_shaker_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+        assert_eq!(tracker.get_comparison_count(), 30);
+        assert_eq!(tracker.get_swap_count(), 22);
+    }
+
+    #[test]
+    fn test_tracked_turtle_needs_fewer_comparisons_than_bubble_sort() {
+        let mut arr: Vec<i32> = (1..100).chain([0]).collect();
+        let mut tracker = SortTracker::new();
+        cocktail_shaker_sort_tracked(&mut arr, &mut tracker);
+
+        let mut bubble_arr: Vec<i32> = (1..100).chain([0]).collect();
+        let mut bubble_tracker = SortTracker::new();
+        bubble_sort_tracked(&mut bubble_arr, &mut bubble_tracker);
+
+        assert_eq!(arr, bubble_arr);
+        assert_eq!(tracker.get_comparison_count(), 294);
+        assert!(tracker.get_comparison_count() * 10 < bubble_tracker.get_comparison_count());
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut arr = vec![("banana", 2), ("apple", 1), ("date", 2), ("cherry", 1)];
+        cocktail_shaker_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 1), ("banana", 2), ("date", 2)]);
+    }
+}
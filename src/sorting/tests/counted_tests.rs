@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod counted_tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::BinaryHeap;
+    use std::hash::Hash;
+    use crate::sorting::counted::{ComparisonCounter, Counted};
+    use crate::sorting::heap_sort::{heap_sort, heap_sort_tracked};
+    use crate::sorting::parallel_merge_sort::{parallel_merge_sort, parallel_merge_sort_tracked};
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::tracker::Tracker;
+    use crate::trees::heaps::heap::Heap;
+    use crate::trees::heaps::min_heap::MinHeap;
+    use crate::trees::heaps::priority_queue::MinPriorityQueue;
+    use crate::util::data_generation::generate_random_vec;
+
+    fn values(counted: Vec<Counted<i32>>) -> Vec<i32> {
+        counted.into_iter().map(Counted::into_inner).collect()
+    }
+
+    #[test]
+    fn test_counts_each_comparison_once() {
+        let counter = ComparisonCounter::new();
+        let a = counter.wrap(1);
+        let b = counter.wrap(2);
+
+        assert!(a < b);
+        assert_eq!(counter.count(), 1);
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Less);
+        assert_eq!(counter.count(), 2);
+        assert!(a != b);
+        assert_eq!(counter.count(), 3);
+    }
+
+    #[test]
+    fn test_hash_clone_and_debug_are_not_counted() {
+        let counter = ComparisonCounter::new();
+        let a = counter.wrap(7);
+        let b = a.clone();
+
+        let mut hasher = DefaultHasher::new();
+        b.hash(&mut hasher);
+        assert_eq!(format!("{:?}", a), "7");
+        assert_eq!(*b.get(), 7);
+        assert_eq!(counter.count(), 0);
+    }
+
+    #[test]
+    fn test_counts_std_slice_sort() {
+        let counter = ComparisonCounter::new();
+        let mut arr = counter.wrap_all(generate_random_vec(1000));
+        arr.sort();
+
+        let sorted = values(arr);
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(counter.count() >= 999);
+    }
+
+    #[test]
+    fn test_counts_std_binary_heap() {
+        let counter = ComparisonCounter::new();
+        let mut heap: BinaryHeap<Counted<i32>> = counter.wrap_all([5, 3, 8, 1]).into_iter().collect();
+        assert!(counter.count() > 0);
+
+        let (largest, comparisons) = counter.measure(|| heap.pop().map(Counted::into_inner));
+        assert_eq!(largest, Some(8));
+        assert!(comparisons > 0);
+    }
+
+    #[test]
+    fn test_counts_generic_heap() {
+        let counter = ComparisonCounter::new();
+        let mut heap: MinHeap<Counted<i32>> = MinHeap::new();
+        for value in counter.wrap_all([4, 2, 9, 1, 7]) {
+            heap.push(value);
+        }
+        let pushes = counter.count();
+        assert!(pushes > 0);
+
+        assert_eq!(heap.pop().map(Counted::into_inner), Some(1));
+        assert!(counter.count() > pushes);
+    }
+
+    #[test]
+    fn test_counts_priority_queue() {
+        let counter = ComparisonCounter::new();
+        let mut queue: MinPriorityQueue<&str, Counted<i32>> = MinPriorityQueue::new();
+        queue.push("b", counter.wrap(2));
+        queue.push("a", counter.wrap(1));
+        queue.push("c", counter.wrap(3));
+
+        assert_eq!(queue.pop(), Some("a"));
+        assert!(counter.count() > 0);
+    }
+
+    #[test]
+    fn test_measure_scopes_a_single_run() {
+        let counter = ComparisonCounter::new();
+        let mut first = counter.wrap_all(generate_random_vec(100));
+        let mut second = counter.wrap_all(generate_random_vec(100));
+
+        let ((), first_comparisons) = counter.measure(|| heap_sort(&mut first));
+        let ((), second_comparisons) = counter.measure(|| heap_sort(&mut second));
+
+        assert_eq!(counter.count(), first_comparisons + second_comparisons);
+        counter.reset();
+        assert_eq!(counter.count(), 0);
+    }
+
+    #[test]
+    fn test_matches_sort_tracker_counts() {
+        let input = generate_random_vec(1000);
+
+        let mut arr = input.clone();
+        let mut tracker = SortTracker::new();
+        heap_sort_tracked(&mut arr, &mut tracker);
+
+        let counter = ComparisonCounter::new();
+        let mut counted = counter.wrap_all(input);
+        heap_sort(&mut counted);
+
+        assert_eq!(counter.count(), tracker.get_comparison_count());
+        assert_eq!(values(counted), arr);
+    }
+
+    #[test]
+    fn test_counts_parallel_sorts() {
+        let input = generate_random_vec(10_000);
+
+        let mut arr = input.clone();
+        let mut tracker = SortTracker::new();
+        parallel_merge_sort_tracked(&mut arr, 4, 64, &mut tracker);
+
+        let counter = ComparisonCounter::new();
+        let mut counted = counter.wrap_all(input);
+        parallel_merge_sort(&mut counted, 4, 64);
+
+        assert_eq!(counter.count(), tracker.get_comparison_count());
+    }
+
+    #[test]
+    fn test_record_into_tracker() {
+        let counter = ComparisonCounter::new();
+        let mut arr = counter.wrap_all([3, 1, 2]);
+        arr.sort();
+        let comparisons = counter.count();
+
+        let mut tracker = SortTracker::new();
+        tracker.record_comparison();
+        counter.record_into(&mut tracker);
+
+        assert_eq!(tracker.get_comparison_count(), comparisons + 1);
+        assert_eq!(counter.count(), 0);
+    }
+}
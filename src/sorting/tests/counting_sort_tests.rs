@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod counting_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::counting_sort::{counting_sort, counting_sort_by_key, counting_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_negative_values() {
+        let mut arr: Vec<i16> = vec![3, -7, 0, -1, 12, -7, 5];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![-7, -7, -1, 0, 3, 5, 12]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1u8];
+        counting_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [u32; 0] = [];
+        counting_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4), vec![7; 5000]] {
+            let mut expected = arr.clone();
+            expected.sort();
+            counting_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_wide_ranges() {
+        let mut arr = vec![i32::MAX, 0, i32::MIN, -1, i32::MAX, 1, i32::MIN];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![i32::MIN, i32::MIN, -1, 0, 1, i32::MAX, i32::MAX]);
+
+        let mut arr = vec![i64::MAX, i64::MIN, 0, i64::MIN + 1, i64::MAX - 1];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![i64::MIN, i64::MIN + 1, 0, i64::MAX - 1, i64::MAX]);
+
+        let mut arr = vec![u64::MAX, 0, u64::MAX, 1];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![0, 1, u64::MAX, u64::MAX]);
+    }
+
+    #[test]
+    fn test_sort_by_key_wide_range_is_stable() {
+        let mut arr = vec![("banana", i64::MAX), ("apple", i64::MIN), ("date", i64::MAX), ("cherry", i64::MIN)];
+        counting_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", i64::MIN), ("cherry", i64::MIN), ("banana", i64::MAX), ("date", i64::MAX)]);
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut arr = vec![("banana", 2u8), ("apple", 1), ("date", 2), ("cherry", 1)];
+        counting_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 1), ("banana", 2), ("date", 2)]);
+    }
+
+    #[test]
+    fn test_tracked_counts() {
+        let mut arr: Vec<u32> = vec![5, 3, 9, 3, 1];
+        let mut tracker = SortTracker::new();
+        counting_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![1, 3, 3, 5, 9]);
+        assert_eq!(tracker.get_comparison_count(), 0);
+        assert_eq!(tracker.get_pass_count(), 1);
+        assert_eq!(tracker.get_bucket_write_count(), 5);
+    }
+
+    #[test]
+    fn test_wide_range_reads_each_key_once() {
+        let mut arr = vec![i32::MAX, 0, i32::MIN, -1, 7];
+        let mut tracker = SortTracker::new();
+        counting_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![i32::MIN, -1, 0, 7, i32::MAX]);
+        assert_eq!(tracker.get_read_count() - tracker.get_move_count(), 5);
+    }
+}
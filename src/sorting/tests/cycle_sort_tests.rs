@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod cycle_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::cycle_sort::{cycle_sort, cycle_sort_by, cycle_sort_by_key, cycle_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        cycle_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        cycle_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        cycle_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        cycle_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(500), generate_sorted_vec(500), generate_reverse_sorted_vec(500), generate_few_unique_vec(500, 4)] {
+            let mut expected = arr.clone();
+            expected.sort();
+            cycle_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        cycle_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        cycle_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        let mut tracker = SortTracker::new();
+        cycle_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+        assert_eq!(tracker.get_comparison_count(), 88);
+        assert_eq!(tracker.get_swap_count(), 6);
+    }
+
+    #[test]
+    fn test_tracked_writes_each_misplaced_element_once() {
+        for arr in [generate_random_vec(300), generate_few_unique_vec(300, 4), generate_reverse_sorted_vec(300), generate_sorted_vec(300)] {
+            let mut expected = arr.clone();
+            expected.sort();
+            let misplaced = arr.iter().zip(&expected).filter(|(a, b)| a != b).count();
+
+            let mut sorted = arr.clone();
+            let mut tracker = SortTracker::new();
+            cycle_sort_tracked(&mut sorted, &mut tracker);
+
+            assert_eq!(sorted, expected);
+            assert_eq!(tracker.get_write_count(), misplaced);
+        }
+    }
+
+    #[test]
+    fn test_tracked_single_cycle_writes() {
+        let mut arr = vec![2, 3, 4, 5, 1];
+        let mut tracker = SortTracker::new();
+        cycle_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+        assert_eq!(tracker.get_write_count(), 5);
+        assert_eq!(tracker.get_swap_count(), 4);
+    }
+}
@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod differential_tests {
+    use std::panic::{self, AssertUnwindSafe};
+    use crate::sorting::dual_pivot_quick_sort::{dual_pivot_quick_sort_by_key, dual_pivot_quick_sort_by_key_tracked};
+    use crate::sorting::parallel_merge_sort::{parallel_merge_sort_by_key, parallel_merge_sort_by_key_tracked};
+    use crate::sorting::parallel_quick_sort::{parallel_quick_sort_by_key, parallel_quick_sort_by_key_tracked};
+    use crate::sorting::quick_sort_mod;
+    use crate::sorting::registry::{find_sort_algorithm, sort_algorithms, SortAlgorithm, SortKey};
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::{heap_sort_with_tracking, insertion_sort_with_tracking, merge_sort_with_tracking, quick_sort_with_tracking, quick_sort_mid_with_tracking, quick_sort_mod_with_tracking};
+    use crate::util::data_generation::{distributions, DataGenerator};
+
+    // A key paired with the position it started at, so the output can be
+    // checked for being a permutation of the input and for stability.
+    type Record = (i32, usize);
+
+    impl SortKey for Record {
+        fn sort_key(&self) -> i32 {
+            self.0
+        }
+    }
+
+    fn with_new_tracker(arr: &mut [Record], sort: fn(&mut [Record], &mut SortTracker)) {
+        sort(arr, &mut SortTracker::new());
+    }
+
+    fn variant(name: &'static str, of: &str, sort: fn(&mut [Record]), sort_tracked: fn(&mut [Record], &mut SortTracker)) -> SortAlgorithm<Record> {
+        SortAlgorithm { name, sort, sort_tracked, ..find_sort_algorithm(of).unwrap() }
+    }
+
+    // The `_with_tracking` wrappers, and small cutoffs so that short inputs
+    // still reach the insertion sort and worker thread paths.
+    fn variants() -> Vec<SortAlgorithm<Record>> {
+        vec![
+            variant("Insertion Sort with tracking", "Insertion Sort", |arr| with_new_tracker(arr, |arr, tracker| insertion_sort_with_tracking::insertion_sort_by_key(arr, Record::sort_key, tracker)), |arr, tracker| insertion_sort_with_tracking::insertion_sort_by_key(arr, Record::sort_key, tracker)),
+            variant("Merge Sort with tracking", "Merge Sort", |arr| with_new_tracker(arr, |arr, tracker| merge_sort_with_tracking::merge_sort_by_key(arr, Record::sort_key, tracker)), |arr, tracker| merge_sort_with_tracking::merge_sort_by_key(arr, Record::sort_key, tracker)),
+            variant("Heap Sort with tracking", "Heap Sort", |arr| with_new_tracker(arr, |arr, tracker| heap_sort_with_tracking::heap_sort_by_key(arr, Record::sort_key, tracker)), |arr, tracker| heap_sort_with_tracking::heap_sort_by_key(arr, Record::sort_key, tracker)),
+            variant("Quick Sort with tracking", "Quick Sort", |arr| with_new_tracker(arr, |arr, tracker| quick_sort_with_tracking::quick_sort_by_key(arr, Record::sort_key, tracker)), |arr, tracker| quick_sort_with_tracking::quick_sort_by_key(arr, Record::sort_key, tracker)),
+            variant("Quick Sort (mid) with tracking", "Quick Sort (mid)", |arr| with_new_tracker(arr, |arr, tracker| quick_sort_mid_with_tracking::quick_sort_by_key(arr, Record::sort_key, tracker)), |arr, tracker| quick_sort_mid_with_tracking::quick_sort_by_key(arr, Record::sort_key, tracker)),
+            variant("Quick Sort (modified) with tracking", "Quick Sort (modified)", |arr| with_new_tracker(arr, |arr, tracker| quick_sort_mod_with_tracking::quick_sort_by_key(arr, 4, Record::sort_key, tracker)), |arr, tracker| quick_sort_mod_with_tracking::quick_sort_by_key(arr, 4, Record::sort_key, tracker)),
+            variant("Quick Sort (modified, cutoff 4)", "Quick Sort (modified)", |arr| quick_sort_mod::quick_sort_by_key(arr, 4, Record::sort_key), |arr, tracker| quick_sort_mod::quick_sort_by_key_tracked(arr, 4, Record::sort_key, tracker)),
+            variant("Dual-Pivot Quick Sort (cutoff 4)", "Dual-Pivot Quick Sort", |arr| dual_pivot_quick_sort_by_key(arr, 4, Record::sort_key), |arr, tracker| dual_pivot_quick_sort_by_key_tracked(arr, 4, Record::sort_key, tracker)),
+            variant("Parallel Merge Sort (cutoff 8)", "Parallel Merge Sort", |arr| parallel_merge_sort_by_key(arr, 4, 8, Record::sort_key), |arr, tracker| parallel_merge_sort_by_key_tracked(arr, 4, 8, Record::sort_key, tracker)),
+            variant("Parallel Quick Sort (cutoff 8)", "Parallel Quick Sort", |arr| parallel_quick_sort_by_key(arr, 4, 4, 8, Record::sort_key), |arr, tracker| parallel_quick_sort_by_key_tracked(arr, 4, 4, 8, Record::sort_key, tracker)),
+        ]
+    }
+
+    const SIZES: [usize; 14] = [0, 1, 2, 3, 4, 5, 8, 16, 17, 31, 64, 100, 257, 600];
+    const SEEDS: [u64; 3] = [1, 2, 3];
+
+    fn records(keys: &[i32]) -> Vec<Record> {
+        keys.iter().copied().zip(0..).collect()
+    }
+
+    fn run(sort: impl FnOnce(&mut [Record]), keys: &[i32]) -> Result<Vec<Record>, String> {
+        let mut arr = records(keys);
+        panic::catch_unwind(AssertUnwindSafe(|| sort(&mut arr)))
+            .map(|()| arr)
+            .map_err(|payload| {
+                let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                format!("panicked: {}", message)
+            })
+    }
+
+    fn check_output(case: &SortAlgorithm<Record>, keys: &[i32], output: &[Record]) -> Result<(), String> {
+        let mut expected = records(keys);
+        expected.sort_by_key(Record::sort_key);
+
+        let mut tags: Vec<usize> = output.iter().map(|record| record.1).collect();
+        tags.sort_unstable();
+        if tags != (0..keys.len()).collect::<Vec<usize>>() {
+            return Err(format!("output {:?} is not a permutation of the input", output));
+        }
+        if output.iter().map(Record::sort_key).ne(expected.iter().map(Record::sort_key)) {
+            return Err(format!("output {:?} is not sorted", output));
+        }
+        if case.stable && output != expected.as_slice() {
+            return Err(format!("output {:?} reorders equal keys, expected {:?}", output, expected));
+        }
+        Ok(())
+    }
+
+    // Runs both the untracked and tracked entry points, which must agree with
+    // `slice::sort` and with each other.
+    fn check(case: &SortAlgorithm<Record>, keys: &[i32]) -> Result<(), String> {
+        let untracked = run(case.sort, keys)?;
+        check_output(case, keys, &untracked)?;
+
+        let mut tracker = SortTracker::new();
+        let tracked = run(|arr| (case.sort_tracked)(arr, &mut tracker), keys).map_err(|e| format!("tracked {}", e))?;
+        check_output(case, keys, &tracked).map_err(|e| format!("tracked {}", e))?;
+        if tracked != untracked {
+            return Err(format!("tracked output {:?} differs from untracked output {:?}", tracked, untracked));
+        }
+        Ok(())
+    }
+
+    // Greedily removes chunks of the input, then moves each remaining key
+    // towards zero in halving steps without crossing it, for as long as the
+    // failure still reproduces.
+    fn shrink<F: Fn(&[i32]) -> bool>(keys: &[i32], fails: F) -> Vec<i32> {
+        let mut current = keys.to_vec();
+        let mut progress = true;
+        while progress {
+            progress = false;
+
+            let mut chunk = current.len() / 2;
+            while chunk > 0 {
+                let mut start = 0;
+                while start + chunk <= current.len() {
+                    let mut candidate = current.clone();
+                    candidate.drain(start..start + chunk);
+                    if fails(&candidate) {
+                        current = candidate;
+                        progress = true;
+                    } else {
+                        start += chunk;
+                    }
+                }
+                chunk /= 2;
+            }
+
+            for i in 0..current.len() {
+                let mut step = current[i];
+                while step != 0 {
+                    let mut candidate = current.clone();
+                    candidate[i] -= step;
+                    if fails(&candidate) {
+                        current = candidate;
+                        progress = true;
+                        if step.abs() > current[i].abs() {
+                            step = current[i];
+                        }
+                    } else {
+                        step /= 2;
+                    }
+                }
+            }
+        }
+        current
+    }
+
+    fn check_against_std(case: &SortAlgorithm<Record>) {
+        for distribution in distributions() {
+            for size in SIZES {
+                for seed in SEEDS {
+                    let keys = (distribution.generate)(&mut DataGenerator::new(seed), size);
+                    if check(case, &keys).is_err() {
+                        let minimal = shrink(&keys, |candidate| check(case, candidate).is_err());
+                        let error = check(case, &minimal).unwrap_err();
+                        panic!("{} failed on {} input of size {} (seed {})\nminimal input: {:?}\n{}", case.name, distribution.name, size, seed, minimal, error);
+                    }
+                }
+            }
+        }
+    }
+
+    fn sort_case(name: &'static str, stable: bool, sort: fn(&mut [Record])) -> SortAlgorithm<Record> {
+        SortAlgorithm { name, stable, sort, sort_tracked: |arr, _| arr.sort_by_key(Record::sort_key), ..sort_algorithms()[0] }
+    }
+
+    #[test]
+    fn test_stable_sorts_match_std() {
+        for algorithm in sort_algorithms().iter().filter(|algorithm| algorithm.stable) {
+            check_against_std(algorithm);
+        }
+    }
+
+    #[test]
+    fn test_unstable_sorts_match_std() {
+        for algorithm in sort_algorithms().iter().filter(|algorithm| !algorithm.stable) {
+            check_against_std(algorithm);
+        }
+    }
+
+    #[test]
+    fn test_variants_match_std() {
+        for algorithm in &variants() {
+            check_against_std(algorithm);
+        }
+    }
+
+    #[test]
+    fn test_detects_unstable_sort_claimed_stable() {
+        let case = SortAlgorithm { stable: true, ..find_sort_algorithm("Heap Sort").unwrap() };
+        let error = check(&case, &[1, 1, 0]).unwrap_err();
+        assert!(error.contains("reorders equal keys"));
+    }
+
+    #[test]
+    fn test_detects_lost_elements() {
+        let case = sort_case("duplicating sort", false, |arr| {
+            arr.sort_by_key(Record::sort_key);
+            if arr.len() > 1 {
+                arr[1] = arr[0];
+            }
+        });
+        let error = check(&case, &[2, 1, 3]).unwrap_err();
+        assert!(error.contains("not a permutation"));
+    }
+
+    #[test]
+    fn test_shrinks_to_minimal_reproducer() {
+        // Ignores the last element, so any input whose largest key is not
+        // already last fails, and the smallest such input is two keys.
+        let case = sort_case("off by one sort", false, |arr| {
+            let size = arr.len();
+            if size > 1 {
+                arr[..size - 1].sort_by_key(Record::sort_key);
+            }
+        });
+        let keys = DataGenerator::new(1).random_vec(100, -1000..1000);
+        assert!(check(&case, &keys).is_err());
+
+        let minimal = shrink(&keys, |candidate| check(&case, candidate).is_err());
+        assert_eq!(minimal.len(), 2);
+        assert!(check(&case, &minimal).is_err());
+        assert!(minimal.iter().all(|&k| k.abs() <= 1));
+    }
+
+    #[test]
+    fn test_shrinks_panicking_sort() {
+        let case = sort_case("panicking sort", false, |arr| assert!(arr.iter().all(|record| record.0 < 500), "key too large"));
+        let minimal = shrink(&[3, 900, -4, 1200, 8], |candidate| check(&case, candidate).is_err());
+        assert_eq!(minimal, vec![500]);
+        assert!(check(&case, &minimal).unwrap_err().contains("key too large"));
+    }
+}
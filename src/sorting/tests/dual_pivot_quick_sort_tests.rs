@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod dual_pivot_quick_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::dual_pivot_quick_sort::{dual_pivot_quick_sort, dual_pivot_quick_sort_by, dual_pivot_quick_sort_by_key, dual_pivot_quick_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_ascending_array() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, vec![10, 20, 30, 40, 50, 60, 70, 80, 90]);
+    }
+
+    #[test]
+    fn test_sort_descending_array() {
+        let mut arr = vec![99, 88, 77, 66, 55, 44, 33, 22, 11];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_two_elements() {
+        let mut arr = [2, 1];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, [1, 2]);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        dual_pivot_quick_sort(&mut arr, 0);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_unsorted_chars_with_cutoff() {
+        let mut arr = vec!['d', 'b', 'a', 'c'];
+        dual_pivot_quick_sort(&mut arr, 3);
+        assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for cutoff in [0, 10] {
+            for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4), vec![7; 5000]] {
+                let mut expected = arr.clone();
+                expected.sort();
+                dual_pivot_quick_sort(&mut arr, cutoff);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_million_element_sorted_input() {
+        let mut arr = generate_sorted_vec(1_000_000);
+        dual_pivot_quick_sort(&mut arr, 10);
+        assert_eq!(arr, generate_sorted_vec(1_000_000));
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        dual_pivot_quick_sort_by(&mut arr, 3, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        dual_pivot_quick_sort_by_key(&mut arr, 0, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        let mut tracker = SortTracker::new();
+        dual_pivot_quick_sort_tracked(&mut arr, 0, &mut tracker);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+        assert_eq!(tracker.get_comparison_count(), 22);
+        assert_eq!(tracker.get_swap_count(), 30);
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod external_sort_tests {
+    use std::fs;
+    use std::io::{Cursor, ErrorKind};
+    use std::path::PathBuf;
+    use crate::sorting::external_sort::{ExternalSort, ExternalSortReport, FixedWidthCodec, LineCodec};
+    use crate::util::data_generation::generate_random_vec;
+
+    // Gives each test its own run directory so leftover files can be detected.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rust_dsa_external_sort_tests_{}_{}", std::process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn assert_no_run_files(dir: &PathBuf) {
+        assert_eq!(fs::read_dir(dir).unwrap().count(), 0);
+        fs::remove_dir(dir).unwrap();
+    }
+
+    fn lines(values: &[i32]) -> String {
+        values.iter().map(|value| format!("{:08}\n", value)).collect()
+    }
+
+    #[test]
+    fn test_sort_lines_in_memory() {
+        let mut output: Vec<u8> = Vec::new();
+        let report = ExternalSort::new(LineCodec).sort(Cursor::new("pear\napple\nfig\n"), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "apple\nfig\npear\n");
+        assert_eq!(report, ExternalSortReport { records: 3, initial_runs: 0, merge_passes: 0 });
+    }
+
+    #[test]
+    fn test_sort_empty_input() {
+        let mut output: Vec<u8> = Vec::new();
+        let report = ExternalSort::new(LineCodec).sort(Cursor::new(""), &mut output).unwrap();
+        assert!(output.is_empty());
+        assert_eq!(report.records, 0);
+    }
+
+    #[test]
+    fn test_sort_lines_without_trailing_newline() {
+        let mut output: Vec<u8> = Vec::new();
+        ExternalSort::new(LineCodec).sort(Cursor::new("b\nc\na"), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "a\nb\nc\n");
+    }
+
+    #[test]
+    fn test_sort_lines_with_single_merge_pass() {
+        let dir = temp_dir("single_pass");
+        let values = generate_random_vec(2000);
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut output: Vec<u8> = Vec::new();
+        let sorter = ExternalSort::new(LineCodec).memory_budget(4 * 1024).fan_in(64).temp_dir(&dir);
+        let report = sorter.sort(Cursor::new(lines(&values)), &mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), lines(&expected));
+        assert_eq!(report.records, 2000);
+        assert!(report.initial_runs > 1);
+        assert_eq!(report.merge_passes, 1);
+        assert_no_run_files(&dir);
+    }
+
+    #[test]
+    fn test_sort_lines_with_multiple_merge_passes() {
+        let dir = temp_dir("multi_pass");
+        let values = generate_random_vec(2000);
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut output: Vec<u8> = Vec::new();
+        let sorter = ExternalSort::new(LineCodec).memory_budget(1024).fan_in(3).temp_dir(&dir);
+        let report = sorter.sort(Cursor::new(lines(&values)), &mut output).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), lines(&expected));
+        assert!(report.initial_runs > 27);
+        assert!(report.merge_passes >= 4);
+        assert_no_run_files(&dir);
+    }
+
+    #[test]
+    fn test_sort_fixed_width_records() {
+        let dir = temp_dir("fixed_width");
+        let values = generate_random_vec(1000);
+        let input: Vec<u8> = values.iter().flat_map(|value| (*value as u32).to_be_bytes()).collect();
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut output: Vec<u8> = Vec::new();
+        let sorter = ExternalSort::new(FixedWidthCodec::new(4)).memory_budget(512).fan_in(4).temp_dir(&dir);
+        let report = sorter.sort(Cursor::new(input), &mut output).unwrap();
+
+        let sorted: Vec<i32> = output.chunks(4).map(|bytes| u32::from_be_bytes(bytes.try_into().unwrap()) as i32).collect();
+        assert_eq!(sorted, expected);
+        assert!(report.merge_passes > 1);
+        assert_no_run_files(&dir);
+    }
+
+    #[test]
+    fn test_sort_duplicate_records_across_runs() {
+        let dir = temp_dir("duplicates");
+        let input: Vec<u8> = (0..200u8).flat_map(|i| [i % 3, i]).collect();
+
+        let mut output: Vec<u8> = Vec::new();
+        let sorter = ExternalSort::new(FixedWidthCodec::new(1)).memory_budget(40).fan_in(2).temp_dir(&dir);
+        sorter.sort(Cursor::new(input), &mut output).unwrap();
+
+        let mut expected: Vec<u8> = (0..200u8).flat_map(|i| [i % 3, i]).collect();
+        expected.sort();
+        assert_eq!(output, expected);
+        assert_no_run_files(&dir);
+    }
+
+    #[test]
+    fn test_sort_truncated_fixed_width_record() {
+        let dir = temp_dir("truncated");
+        let mut output: Vec<u8> = Vec::new();
+        let sorter = ExternalSort::new(FixedWidthCodec::new(4)).memory_budget(64).temp_dir(&dir);
+        let error = sorter.sort(Cursor::new(vec![0u8; 4 * 20 + 2]), &mut output).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        assert_no_run_files(&dir);
+    }
+}
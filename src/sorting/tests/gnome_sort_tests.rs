@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod gnome_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::gnome_sort::{gnome_sort, gnome_sort_by, gnome_sort_by_key, gnome_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+    use crate::sorting::insertion_sort::insertion_sort_tracked;
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        gnome_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        gnome_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        gnome_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        gnome_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(500), generate_sorted_vec(500), generate_reverse_sorted_vec(500), generate_few_unique_vec(500, 4)] {
+            let mut expected = arr.clone();
+            expected.sort();
+            gnome_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        gnome_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        gnome_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        let mut tracker = SortTracker::new();
+        gnome_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+        assert_eq!(tracker.get_comparison_count(), 50);
+        assert_eq!(tracker.get_swap_count(), 22);
+    }
+
+    #[test]
+    fn test_tracked_swaps_match_insertion_sort() {
+        let arr = generate_random_vec(300);
+
+        let mut gnome_tracker = SortTracker::new();
+        gnome_sort_tracked(&mut arr.clone(), &mut gnome_tracker);
+
+        let mut insertion_tracker = SortTracker::new();
+        insertion_sort_tracked(&mut arr.clone(), &mut insertion_tracker);
+
+        assert_eq!(gnome_tracker.get_swap_count(), insertion_tracker.get_swap_count());
+    }
+}
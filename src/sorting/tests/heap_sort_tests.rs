@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod heap_sort_tests {
-    use crate::sorting::heap_sort::heap_sort;
+    use std::num::NonZeroU32;
+    use crate::sorting::heap_sort::{heap_sort, heap_sort_by, heap_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -78,4 +79,26 @@ mod heap_sort_tests {
         heap_sort(&mut arr);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
     }
-}
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        heap_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        heap_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_sort_non_default_values() {
+        let mut arr: Vec<NonZeroU32> = [33, 18, 78, 64, 45, 32, 70, 11, 27].iter().map(|&n| NonZeroU32::new(n).unwrap()).collect();
+        heap_sort(&mut arr);
+        let sorted: Vec<u32> = arr.iter().map(|n| n.get()).collect();
+        assert_eq!(sorted, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+}
\ No newline at end of file
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod heap_sort_with_tracking_tests {
     use crate::sorting::sort_tracker::SortTracker;
-    use crate::sorting::heap_sort_with_tracking::heap_sort;
+    use crate::sorting::heap_sort_with_tracking::{heap_sort, heap_sort_by, heap_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -10,7 +10,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
         assert_eq!(tracker.get_comparison_count(), 31);
-        assert_eq!(tracker.get_swap_count(), 14);
+        assert_eq!(tracker.get_swap_count(), 22);
     }
 
     #[test]
@@ -20,7 +20,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec![10, 20, 30, 40, 50, 60, 70, 80, 90]);
         assert_eq!(tracker.get_comparison_count(), 35);
-        assert_eq!(tracker.get_swap_count(), 18);
+        assert_eq!(tracker.get_swap_count(), 26);
     }
 
     #[test]
@@ -30,7 +30,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
         assert_eq!(tracker.get_comparison_count(), 30);
-        assert_eq!(tracker.get_swap_count(), 12);
+        assert_eq!(tracker.get_swap_count(), 20);
     }
 
     #[test]
@@ -60,7 +60,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
         assert_eq!(tracker.get_comparison_count(), 7);
-        assert_eq!(tracker.get_swap_count(), 4);
+        assert_eq!(tracker.get_swap_count(), 7);
     }
 
     #[test]
@@ -70,7 +70,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
         assert_eq!(tracker.get_comparison_count(), 7);
-        assert_eq!(tracker.get_swap_count(), 5);
+        assert_eq!(tracker.get_swap_count(), 8);
     }
 
     #[test]
@@ -80,7 +80,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
         assert_eq!(tracker.get_comparison_count(), 6);
-        assert_eq!(tracker.get_swap_count(), 1);
+        assert_eq!(tracker.get_swap_count(), 4);
     }
 
     #[test]
@@ -90,7 +90,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
         assert_eq!(tracker.get_comparison_count(), 6);
-        assert_eq!(tracker.get_swap_count(), 3);
+        assert_eq!(tracker.get_swap_count(), 6);
     }
 
     #[test]
@@ -100,7 +100,7 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
         assert_eq!(tracker.get_comparison_count(), 7);
-        assert_eq!(tracker.get_swap_count(), 5);
+        assert_eq!(tracker.get_swap_count(), 8);
     }
 
     #[test]
@@ -110,6 +110,31 @@ mod heap_sort_with_tracking_tests {
         heap_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
         assert_eq!(tracker.get_comparison_count(), 6);
-        assert_eq!(tracker.get_swap_count(), 1);
+        assert_eq!(tracker.get_swap_count(), 4);
+    }
+
+    #[test]
+    fn test_sort_by_counts_comparator_calls() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        let mut tracker = SortTracker::new();
+        let mut calls = 0;
+        heap_sort_by(&mut arr, |a, b| {
+            calls += 1;
+            b.cmp(a)
+        }, &mut tracker);
+        assert_eq!(arr, vec![90, 80, 70, 60, 50, 40, 30, 20, 10]);
+        assert_eq!(tracker.get_comparison_count(), 30);
+        assert_eq!(tracker.get_swap_count(), 20);
+        assert_eq!(calls, 30);
+    }
+
+    #[test]
+    fn test_sort_by_key_counts_key_comparisons() {
+        let mut arr = vec![("a", 99), ("b", 88), ("c", 77), ("d", 66), ("e", 55), ("f", 44), ("g", 33), ("h", 22), ("i", 11)];
+        let mut tracker = SortTracker::new();
+        heap_sort_by_key(&mut arr, |&(_, value)| value, &mut tracker);
+        assert_eq!(arr, vec![("i", 11), ("h", 22), ("g", 33), ("f", 44), ("e", 55), ("d", 66), ("c", 77), ("b", 88), ("a", 99)]);
+        assert_eq!(tracker.get_comparison_count(), 30);
+        assert_eq!(tracker.get_swap_count(), 20);
     }
-}
+}
\ No newline at end of file
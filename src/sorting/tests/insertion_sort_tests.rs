@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod insertion_sort_tests {
-    use crate::sorting::insertion_sort::insertion_sort;
+    use crate::sorting::insertion_sort::{insertion_sort, insertion_sort_by, insertion_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -78,4 +78,18 @@ mod insertion_sort_tests {
         insertion_sort(&mut arr);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
     }
-}
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        insertion_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        insertion_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+}
\ No newline at end of file
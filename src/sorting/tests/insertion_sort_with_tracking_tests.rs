@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod insertion_sort_with_tracking_tests {
     use crate::sorting::sort_tracker::SortTracker;
-    use crate::sorting::insertion_sort_with_tracking::insertion_sort;
+    use crate::sorting::insertion_sort_with_tracking::{insertion_sort, insertion_sort_by, insertion_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -112,4 +112,29 @@ mod insertion_sort_with_tracking_tests {
         assert_eq!(tracker.get_comparison_count(), 6);
         assert_eq!(tracker.get_swap_count(), 6);
     }
-}
+
+    #[test]
+    fn test_sort_by_counts_comparator_calls() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        let mut tracker = SortTracker::new();
+        let mut calls = 0;
+        insertion_sort_by(&mut arr, |a, b| {
+            calls += 1;
+            b.cmp(a)
+        }, &mut tracker);
+        assert_eq!(arr, vec![90, 80, 70, 60, 50, 40, 30, 20, 10]);
+        assert_eq!(tracker.get_comparison_count(), 36);
+        assert_eq!(tracker.get_swap_count(), 36);
+        assert_eq!(calls, 36);
+    }
+
+    #[test]
+    fn test_sort_by_key_counts_key_comparisons() {
+        let mut arr = vec![("a", 99), ("b", 88), ("c", 77), ("d", 66), ("e", 55), ("f", 44), ("g", 33), ("h", 22), ("i", 11)];
+        let mut tracker = SortTracker::new();
+        insertion_sort_by_key(&mut arr, |&(_, value)| value, &mut tracker);
+        assert_eq!(arr, vec![("i", 11), ("h", 22), ("g", 33), ("f", 44), ("e", 55), ("d", 66), ("c", 77), ("b", 88), ("a", 99)]);
+        assert_eq!(tracker.get_comparison_count(), 36);
+        assert_eq!(tracker.get_swap_count(), 36);
+    }
+}
\ No newline at end of file
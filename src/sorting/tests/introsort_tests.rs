@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod introsort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::introsort::{introsort, introsort_by, introsort_by_key, introsort_tracked};
+    use crate::sorting::quick_sort_mod;
+    use crate::util::data_generation::{generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    // Builds an input that drives `quick_sort_mod` to quadratic behaviour using
+    // McIlroy's adversary: values are assigned lazily so that every pivot the
+    // sort picks turns out to be among the smallest remaining elements.
+    fn quick_sort_mod_killer(size: usize, cutoff: usize) -> Vec<usize> {
+        let gas = size;
+        let mut values = vec![gas; size];
+        let mut solid = 0;
+        let mut candidate = 0;
+        let mut items: Vec<usize> = (0..size).collect();
+
+        quick_sort_mod::quick_sort_by(&mut items, cutoff, |&x, &y| {
+            if values[x] == gas && values[y] == gas {
+                let frozen = if x == candidate { x } else { y };
+                values[frozen] = solid;
+                solid += 1;
+            }
+            if values[x] == gas {
+                candidate = x;
+            } else if values[y] == gas {
+                candidate = y;
+            }
+            values[x].cmp(&values[y])
+        });
+
+        values
+    }
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        introsort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        introsort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        introsort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        introsort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), vec![7; 5000]] {
+            let mut expected = arr.clone();
+            expected.sort();
+            introsort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        introsort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        introsort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_killer_input_degrades_quick_sort_mod() {
+        let size = 2000;
+        let mut arr = quick_sort_mod_killer(size, 10);
+        let mut tracker = SortTracker::new();
+        quick_sort_mod::quick_sort_tracked(&mut arr, 10, &mut tracker);
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(tracker.get_comparison_count() > size * size / 8);
+    }
+
+    #[test]
+    fn test_killer_input_stays_n_log_n() {
+        let size = 2000;
+        let mut arr = quick_sort_mod_killer(size, 10);
+        let mut tracker = SortTracker::new();
+        introsort_tracked(&mut arr, &mut tracker);
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let n_log_n = size * size.ilog2() as usize;
+        assert!(tracker.get_comparison_count() < 5 * n_log_n);
+    }
+
+}
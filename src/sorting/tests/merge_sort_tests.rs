@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod merge_sort_tests {
-    use crate::sorting::merge_sort::merge_sort;
+    use std::num::NonZeroU32;
+    use crate::sorting::merge_sort::{merge_sort, merge_sort_by, merge_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -78,4 +79,48 @@ mod merge_sort_tests {
         merge_sort(&mut arr);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
     }
-}
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        merge_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        merge_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut arr = vec![("b", 2), ("a", 1), ("c", 2), ("d", 1), ("e", 2)];
+        merge_sort_by_key(&mut arr, |&(_, group)| group);
+        assert_eq!(arr, vec![("a", 1), ("d", 1), ("b", 2), ("c", 2), ("e", 2)]);
+    }
+
+    #[test]
+    fn test_sort_non_default_values() {
+        let mut arr: Vec<NonZeroU32> = [33, 18, 78, 64, 45, 32, 70, 11, 27].iter().map(|&n| NonZeroU32::new(n).unwrap()).collect();
+        merge_sort(&mut arr);
+        let sorted: Vec<u32> = arr.iter().map(|n| n.get()).collect();
+        assert_eq!(sorted, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_by_key_non_default_records_is_stable() {
+        #[derive(Debug, PartialEq)]
+        struct Record {
+            name: &'static str,
+            group: NonZeroU32,
+        }
+
+        let record = |name, group| Record { name, group: NonZeroU32::new(group).unwrap() };
+        let mut arr = vec![record("b", 2), record("a", 1), record("c", 2), record("d", 1), record("e", 2)];
+        merge_sort_by_key(&mut arr, |record| record.group);
+        let names: Vec<&str> = arr.iter().map(|record| record.name).collect();
+        assert_eq!(names, vec!["a", "d", "b", "c", "e"]);
+    }
+}
\ No newline at end of file
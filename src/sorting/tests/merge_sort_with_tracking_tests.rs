@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod merge_sort_with_tracking_tests {
     use crate::sorting::sort_tracker::SortTracker;
-    use crate::sorting::merge_sort_with_tracking::merge_sort;
+    use crate::sorting::merge_sort_with_tracking::{merge_sort, merge_sort_by, merge_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -30,6 +30,26 @@ mod merge_sort_with_tracking_tests {
         assert_eq!(tracker.get_comparison_count(), 13);
     }
 
+    #[test]
+    fn test_reports_moves_without_swaps() {
+        let mut arr = vec![99, 88, 77, 66, 55, 44, 33, 22, 11];
+        let mut tracker = SortTracker::new();
+        merge_sort(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
+        assert_eq!(tracker.get_swap_count(), 0);
+        assert_eq!(tracker.get_move_count(), 40);
+        assert_eq!(tracker.get_read_count(), 66);
+    }
+
+    #[test]
+    fn test_peak_auxiliary_memory_is_the_largest_merge() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        let mut tracker = SortTracker::new();
+        merge_sort(&mut arr, &mut tracker);
+        assert_eq!(tracker.get_peak_auxiliary_memory(), 9 * size_of::<usize>());
+        assert!(tracker.get_auxiliary_memory() > tracker.get_peak_auxiliary_memory());
+    }
+
     #[test]
     fn test_sort_single_element() {
         let mut arr = [1];
@@ -101,4 +121,27 @@ mod merge_sort_with_tracking_tests {
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
         assert_eq!(tracker.get_comparison_count(), 4);
     }
-}
+
+    #[test]
+    fn test_sort_by_counts_comparator_calls() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        let mut tracker = SortTracker::new();
+        let mut calls = 0;
+        merge_sort_by(&mut arr, |a, b| {
+            calls += 1;
+            b.cmp(a)
+        }, &mut tracker);
+        assert_eq!(arr, vec![90, 80, 70, 60, 50, 40, 30, 20, 10]);
+        assert_eq!(tracker.get_comparison_count(), 13);
+        assert_eq!(calls, 13);
+    }
+
+    #[test]
+    fn test_sort_by_key_counts_key_comparisons() {
+        let mut arr = vec![("a", 99), ("b", 88), ("c", 77), ("d", 66), ("e", 55), ("f", 44), ("g", 33), ("h", 22), ("i", 11)];
+        let mut tracker = SortTracker::new();
+        merge_sort_by_key(&mut arr, |&(_, value)| value, &mut tracker);
+        assert_eq!(arr, vec![("i", 11), ("h", 22), ("g", 33), ("f", 44), ("e", 55), ("d", 66), ("c", 77), ("b", 88), ("a", 99)]);
+        assert_eq!(tracker.get_comparison_count(), 13);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod odd_even_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::odd_even_sort::{odd_even_sort, odd_even_sort_by, odd_even_sort_by_key, odd_even_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        odd_even_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        odd_even_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        odd_even_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        odd_even_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(500), generate_sorted_vec(500), generate_reverse_sorted_vec(500), generate_few_unique_vec(500, 4)] {
+            let mut expected = arr.clone();
+            expected.sort();
+            odd_even_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        odd_even_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        odd_even_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        let mut tracker = SortTracker::new();
+        odd_even_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+        assert_eq!(tracker.get_comparison_count(), 40);
+        assert_eq!(tracker.get_swap_count(), 22);
+    }
+
+    #[test]
+    fn test_tracked_sorted_input_exits_after_one_round() {
+        let mut arr = generate_sorted_vec(300);
+        let mut tracker = SortTracker::new();
+        odd_even_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(tracker.get_comparison_count(), 299);
+        assert_eq!(tracker.get_swap_count(), 0);
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod parallel_merge_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::merge_sort::merge_sort_tracked;
+    use crate::sorting::parallel_merge_sort::{parallel_merge_sort, parallel_merge_sort_by, parallel_merge_sort_by_key, parallel_merge_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        parallel_merge_sort(&mut arr, 4, 2);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        parallel_merge_sort(&mut arr, 4, 0);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        parallel_merge_sort(&mut arr, 4, 0);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        parallel_merge_sort(&mut arr, 2, 1);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs_with_various_thread_counts() {
+        for threads in [0, 1, 2, 3, 8] {
+            for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4)] {
+                let mut expected = arr.clone();
+                expected.sort();
+                parallel_merge_sort(&mut arr, threads, 100);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        parallel_merge_sort_by(&mut arr, 4, 1, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut arr = vec![("banana", 2), ("apple", 1), ("date", 2), ("cherry", 1)];
+        parallel_merge_sort_by_key(&mut arr, 4, 1, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 1), ("banana", 2), ("date", 2)]);
+    }
+
+    #[test]
+    fn test_tracked_counts_match_sequential_merge_sort() {
+        let arr = generate_random_vec(10_000);
+
+        let mut sequential = arr.clone();
+        let mut sequential_tracker = SortTracker::new();
+        merge_sort_tracked(&mut sequential, &mut sequential_tracker);
+
+        for threads in [2, 4, 7] {
+            let mut parallel = arr.clone();
+            let mut parallel_tracker = SortTracker::new();
+            parallel_merge_sort_tracked(&mut parallel, threads, 64, &mut parallel_tracker);
+
+            assert_eq!(parallel, sequential);
+            assert_eq!(parallel_tracker.get_comparison_count(), sequential_tracker.get_comparison_count());
+            assert_eq!(parallel_tracker.get_swap_count(), sequential_tracker.get_swap_count());
+            assert_eq!(parallel_tracker.get_read_count(), sequential_tracker.get_read_count());
+            assert_eq!(parallel_tracker.get_move_count(), sequential_tracker.get_move_count());
+            assert_eq!(parallel_tracker.get_auxiliary_memory(), sequential_tracker.get_auxiliary_memory());
+            assert_eq!(parallel_tracker.get_peak_auxiliary_memory(), sequential_tracker.get_peak_auxiliary_memory());
+        }
+    }
+}
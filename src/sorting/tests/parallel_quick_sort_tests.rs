@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod parallel_quick_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::quick_sort_mod::quick_sort_tracked;
+    use crate::sorting::parallel_quick_sort::{parallel_quick_sort, parallel_quick_sort_by, parallel_quick_sort_by_key, parallel_quick_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        parallel_quick_sort(&mut arr, 0, 4, 2);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        parallel_quick_sort(&mut arr, 0, 4, 0);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        parallel_quick_sort(&mut arr, 0, 4, 0);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_chars_with_cutoff() {
+        let mut arr = vec!['d', 'b', 'a', 'c'];
+        parallel_quick_sort(&mut arr, 3, 2, 0);
+        assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn test_sort_large_inputs_with_various_thread_counts() {
+        for threads in [0, 1, 2, 3, 8] {
+            for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4)] {
+                let mut expected = arr.clone();
+                expected.sort();
+                parallel_quick_sort(&mut arr, 10, threads, 100);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        parallel_quick_sort_by(&mut arr, 0, 4, 1, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        parallel_quick_sort_by_key(&mut arr, 0, 4, 1, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts_match_sequential_quick_sort() {
+        let arr = generate_random_vec(10_000);
+
+        let mut sequential = arr.clone();
+        let mut sequential_tracker = SortTracker::new();
+        quick_sort_tracked(&mut sequential, 10, &mut sequential_tracker);
+
+        for threads in [2, 4, 7] {
+            let mut parallel = arr.clone();
+            let mut parallel_tracker = SortTracker::new();
+            parallel_quick_sort_tracked(&mut parallel, 10, threads, 64, &mut parallel_tracker);
+
+            assert_eq!(parallel, sequential);
+            assert_eq!(parallel_tracker.get_comparison_count(), sequential_tracker.get_comparison_count());
+            assert_eq!(parallel_tracker.get_swap_count(), sequential_tracker.get_swap_count());
+        }
+    }
+}
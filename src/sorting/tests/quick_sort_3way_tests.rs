@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod quick_sort_3way_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::quick_sort_3way::{quick_sort, quick_sort_by, quick_sort_by_key, quick_sort_tracked};
+    use crate::sorting::quick_sort_mid;
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_ascending_array() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![10, 20, 30, 40, 50, 60, 70, 80, 90]);
+    }
+
+    #[test]
+    fn test_sort_descending_array() {
+        let mut arr = vec![99, 88, 77, 66, 55, 44, 33, 22, 11];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        quick_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        quick_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date", "apple", "banana"];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "apple", "banana", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_duplicate_heavy_array() {
+        let mut arr = vec![3, 1, 2, 3, 3, 1, 2, 2, 3, 1, 1, 3];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![1, 1, 1, 1, 2, 2, 2, 3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4), vec![7; 5000]] {
+            let mut expected = arr.clone();
+            expected.sort();
+            quick_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        quick_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        quick_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_all_equal_input_is_linear() {
+        let mut arr = vec![5; 1000];
+        let mut tracker = SortTracker::new();
+        quick_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![5; 1000]);
+        assert_eq!(tracker.get_comparison_count(), 999);
+    }
+
+    #[test]
+    fn test_few_unique_uses_fewer_comparisons_than_two_way() {
+        let input = generate_few_unique_vec(2000, 5);
+
+        let mut arr = input.clone();
+        let mut three_way = SortTracker::new();
+        quick_sort_tracked(&mut arr, &mut three_way);
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let mut arr = input;
+        let mut two_way = SortTracker::new();
+        quick_sort_mid::quick_sort_tracked(&mut arr, &mut two_way);
+
+        assert!(three_way.get_comparison_count() * 10 < two_way.get_comparison_count());
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod quick_sort_tests {
-    use crate::sorting::quick_sort_mod::quick_sort;
+    use crate::util::data_generation::{generate_reverse_sorted_vec, generate_sorted_vec};
+    use crate::sorting::quick_sort_mod::{quick_sort, quick_sort_by, quick_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -78,4 +79,32 @@ mod quick_sort_tests {
         quick_sort(&mut arr, 10);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
     }
-}
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        quick_sort_by(&mut arr, 3, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        quick_sort_by_key(&mut arr, 3, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_sort_million_element_sorted_input() {
+        let mut arr = generate_sorted_vec(1_000_000);
+        quick_sort(&mut arr, 10);
+        assert_eq!(arr, generate_sorted_vec(1_000_000));
+    }
+
+    #[test]
+    fn test_sort_million_element_reverse_sorted_input() {
+        let mut arr = generate_reverse_sorted_vec(1_000_000);
+        quick_sort(&mut arr, 10);
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
\ No newline at end of file
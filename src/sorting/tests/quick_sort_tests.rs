@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod quick_sort_tests {
-    use crate::sorting::quick_sort::quick_sort;
+    use std::thread;
+    use crate::util::data_generation::{generate_reverse_sorted_vec, generate_sorted_vec};
+    use crate::sorting::quick_sort::{quick_sort, quick_sort_by, quick_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -78,4 +80,38 @@ mod quick_sort_tests {
         quick_sort(&mut arr);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
     }
-}
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        quick_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        quick_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_sort_sorted_input_with_bounded_stack() {
+        // The first-element pivot makes sorted input quadratic, so a million
+        // elements would take far too long. Instead, sort on a thread whose
+        // stack is too small to hold one frame per element.
+        let handle = thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let mut sorted = generate_sorted_vec(5_000);
+                quick_sort(&mut sorted);
+                assert_eq!(sorted, generate_sorted_vec(5_000));
+
+                let mut reversed = generate_reverse_sorted_vec(5_000);
+                quick_sort(&mut reversed);
+                assert!(reversed.windows(2).all(|pair| pair[0] <= pair[1]));
+            })
+            .unwrap();
+        handle.join().unwrap();
+    }
+}
\ No newline at end of file
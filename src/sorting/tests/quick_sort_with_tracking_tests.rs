@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod quick_sort_with_tracking_tests {
     use crate::sorting::sort_tracker::SortTracker;
-    use crate::sorting::quick_sort_with_tracking::quick_sort;
+    use crate::sorting::quick_sort_with_tracking::{quick_sort, quick_sort_by, quick_sort_by_key};
 
     #[test]
     fn test_sort_unsorted_array() {
@@ -9,8 +9,8 @@ mod quick_sort_with_tracking_tests {
         let mut tracker = SortTracker::new();
         quick_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
-        assert_eq!(tracker.get_comparison_count(), 25);
-        assert_eq!(tracker.get_swap_count(), 19);
+        assert_eq!(tracker.get_comparison_count(), 18);
+        assert_eq!(tracker.get_swap_count(), 16);
     }
 
     #[test]
@@ -29,8 +29,8 @@ mod quick_sort_with_tracking_tests {
         let mut tracker = SortTracker::new();
         quick_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
-        assert_eq!(tracker.get_comparison_count(), 44);
-        assert_eq!(tracker.get_swap_count(), 29);
+        assert_eq!(tracker.get_comparison_count(), 36);
+        assert_eq!(tracker.get_swap_count(), 28);
     }
 
     #[test]
@@ -59,8 +59,8 @@ mod quick_sort_with_tracking_tests {
         let mut tracker = SortTracker::new();
         quick_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
-        assert_eq!(tracker.get_comparison_count(), 5);
-        assert_eq!(tracker.get_swap_count(), 4);
+        assert_eq!(tracker.get_comparison_count(), 4);
+        assert_eq!(tracker.get_swap_count(), 3);
     }
 
     #[test]
@@ -79,8 +79,8 @@ mod quick_sort_with_tracking_tests {
         let mut tracker = SortTracker::new();
         quick_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
-        assert_eq!(tracker.get_comparison_count(), 9);
-        assert_eq!(tracker.get_swap_count(), 8);
+        assert_eq!(tracker.get_comparison_count(), 6);
+        assert_eq!(tracker.get_swap_count(), 7);
     }
 
     #[test]
@@ -89,8 +89,8 @@ mod quick_sort_with_tracking_tests {
         let mut tracker = SortTracker::new();
         quick_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
-        assert_eq!(tracker.get_comparison_count(), 9);
-        assert_eq!(tracker.get_swap_count(), 9);
+        assert_eq!(tracker.get_comparison_count(), 6);
+        assert_eq!(tracker.get_swap_count(), 8);
     }
 
     #[test]
@@ -109,7 +109,32 @@ mod quick_sort_with_tracking_tests {
         let mut tracker = SortTracker::new();
         quick_sort(&mut arr, &mut tracker);
         assert_eq!(arr, vec!['a', 'b', 'c', 'd']);
-        assert_eq!(tracker.get_comparison_count(), 9);
-        assert_eq!(tracker.get_swap_count(), 8);
+        assert_eq!(tracker.get_comparison_count(), 6);
+        assert_eq!(tracker.get_swap_count(), 7);
+    }
+
+    #[test]
+    fn test_sort_by_counts_comparator_calls() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        let mut tracker = SortTracker::new();
+        let mut calls = 0;
+        quick_sort_by(&mut arr, |a, b| {
+            calls += 1;
+            b.cmp(a)
+        }, &mut tracker);
+        assert_eq!(arr, vec![90, 80, 70, 60, 50, 40, 30, 20, 10]);
+        assert_eq!(tracker.get_comparison_count(), 36);
+        assert_eq!(tracker.get_swap_count(), 28);
+        assert_eq!(calls, 36);
+    }
+
+    #[test]
+    fn test_sort_by_key_counts_key_comparisons() {
+        let mut arr = vec![("a", 99), ("b", 88), ("c", 77), ("d", 66), ("e", 55), ("f", 44), ("g", 33), ("h", 22), ("i", 11)];
+        let mut tracker = SortTracker::new();
+        quick_sort_by_key(&mut arr, |&(_, value)| value, &mut tracker);
+        assert_eq!(arr, vec![("i", 11), ("h", 22), ("g", 33), ("f", 44), ("e", 55), ("d", 66), ("c", 77), ("b", 88), ("a", 99)]);
+        assert_eq!(tracker.get_comparison_count(), 36);
+        assert_eq!(tracker.get_swap_count(), 28);
     }
-}
+}
\ No newline at end of file
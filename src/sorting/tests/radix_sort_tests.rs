@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod radix_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::radix_sort::{lsd_radix_sort, lsd_radix_sort_by_key, lsd_radix_sort_tracked, msd_radix_sort, msd_radix_sort_by_key, msd_radix_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_lsd_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_lsd_sort_signed_extremes() {
+        let mut arr: Vec<i64> = vec![0, i64::MAX, -1, i64::MIN, 1, -300, 300];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![i64::MIN, -300, -1, 0, 1, 300, i64::MAX]);
+
+        let mut arr: Vec<i8> = vec![127, -128, 0, -1, 1];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![-128, -1, 0, 1, 127]);
+    }
+
+    #[test]
+    fn test_lsd_sort_unsigned_extremes() {
+        let mut arr: Vec<u32> = vec![u32::MAX, 0, 1 << 24, 255, 256];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![0, 255, 256, 1 << 24, u32::MAX]);
+    }
+
+    #[test]
+    fn test_lsd_sort_single_element() {
+        let mut arr = [1];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_lsd_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        lsd_radix_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_lsd_sort_large_inputs() {
+        for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4)] {
+            arr.iter_mut().step_by(3).for_each(|value| *value = -*value);
+            let mut expected = arr.clone();
+            expected.sort();
+            lsd_radix_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_lsd_sort_by_key_is_stable() {
+        let mut arr = vec![("banana", 2u16), ("apple", 1), ("date", 2), ("cherry", 1)];
+        lsd_radix_sort_by_key(&mut arr, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 1), ("banana", 2), ("date", 2)]);
+    }
+
+    #[test]
+    fn test_lsd_tracked_skips_constant_bytes() {
+        let mut arr: Vec<u32> = vec![300, 5, 256, 40, 2];
+        let mut tracker = SortTracker::new();
+        lsd_radix_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec![2, 5, 40, 256, 300]);
+        assert_eq!(tracker.get_comparison_count(), 0);
+        assert_eq!(tracker.get_pass_count(), 2);
+        assert_eq!(tracker.get_bucket_write_count(), 10);
+    }
+
+    #[test]
+    fn test_msd_sort_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "app", "", "apples", "date", "b"];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec!["", "app", "apple", "apples", "b", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_msd_sort_byte_vectors() {
+        let mut arr: Vec<Vec<u8>> = vec![vec![255, 0], vec![0], vec![255], vec![0, 0, 1], vec![0, 0]];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![vec![0], vec![0, 0], vec![0, 0, 1], vec![255], vec![255, 0]]);
+    }
+
+    #[test]
+    fn test_msd_sort_single_element() {
+        let mut arr = ["only"];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, ["only"]);
+    }
+
+    #[test]
+    fn test_msd_sort_empty_array() {
+        let mut arr: [String; 0] = [];
+        msd_radix_sort(&mut arr);
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn test_msd_sort_long_common_prefix() {
+        let prefix = "x".repeat(20_000);
+        let mut arr = vec![format!("{prefix}c"), format!("{prefix}a"), prefix.clone(), format!("{prefix}b")];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![prefix.clone(), format!("{prefix}a"), format!("{prefix}b"), format!("{prefix}c")]);
+    }
+
+    #[test]
+    fn test_msd_sort_large_inputs() {
+        let mut arr: Vec<String> = generate_random_vec(5000).iter().map(|value| value.to_string()).collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_msd_sort_by_key_is_stable() {
+        let mut arr = vec![(1, "pear"), (2, "fig"), (3, "pear"), (4, "apple")];
+        msd_radix_sort_by_key(&mut arr, |&(_, name)| name);
+        assert_eq!(arr, vec![(4, "apple"), (2, "fig"), (1, "pear"), (3, "pear")]);
+    }
+
+    #[test]
+    fn test_msd_tracked_counts() {
+        let mut arr = vec!["cab", "abc", "cba", "abd"];
+        let mut tracker = SortTracker::new();
+        msd_radix_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, vec!["abc", "abd", "cab", "cba"]);
+        assert_eq!(tracker.get_comparison_count(), 0);
+        assert_eq!(tracker.get_pass_count(), 3);
+        assert_eq!(tracker.get_bucket_write_count(), 8);
+    }
+}
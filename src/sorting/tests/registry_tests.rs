@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod registry_tests {
+    use std::collections::HashSet;
+    use crate::sorting::registry::{find_sort_algorithm, sort_algorithms};
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::util::data_generation::{generate_random_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_names_are_unique() {
+        let algorithms = sort_algorithms::<i32>();
+        let names: HashSet<&str> = algorithms.iter().map(|algorithm| algorithm.name).collect();
+        assert_eq!(names.len(), algorithms.len());
+    }
+
+    #[test]
+    fn test_every_algorithm_sorts() {
+        let input = generate_random_vec(500);
+        let mut expected = input.clone();
+        expected.sort();
+
+        for algorithm in sort_algorithms::<i32>() {
+            let mut arr = input.clone();
+            (algorithm.sort)(&mut arr);
+            assert_eq!(arr, expected, "{}", algorithm.name);
+        }
+    }
+
+    #[test]
+    fn test_tracked_entry_points_record_work() {
+        let input = generate_random_vec(500);
+
+        for algorithm in sort_algorithms::<i32>() {
+            let mut arr = input.clone();
+            let mut tracker = SortTracker::new();
+            (algorithm.sort_tracked)(&mut arr, &mut tracker);
+            assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]), "{}", algorithm.name);
+            assert!(tracker.get_read_count() > 0, "{}", algorithm.name);
+        }
+    }
+
+    #[test]
+    fn test_in_place_sorts_allocate_no_buffers() {
+        let mut input = generate_random_vec(500);
+        input.reverse();
+
+        for algorithm in sort_algorithms::<i32>() {
+            let mut arr = input.clone();
+            let mut tracker = SortTracker::new();
+            (algorithm.sort_tracked)(&mut arr, &mut tracker);
+            assert_eq!(algorithm.in_place, tracker.get_peak_auxiliary_memory() == 0, "{}", algorithm.name);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_sorts_do_less_work_on_sorted_input() {
+        let sorted = generate_sorted_vec(1000);
+        let random = generate_random_vec(1000);
+
+        for algorithm in sort_algorithms::<i32>().into_iter().filter(|algorithm| algorithm.adaptive) {
+            let mut sorted_tracker = SortTracker::new();
+            (algorithm.sort_tracked)(&mut sorted.clone(), &mut sorted_tracker);
+            let mut random_tracker = SortTracker::new();
+            (algorithm.sort_tracked)(&mut random.clone(), &mut random_tracker);
+            assert!(sorted_tracker.get_comparison_count() < random_tracker.get_comparison_count(), "{}", algorithm.name);
+        }
+    }
+
+    #[test]
+    fn test_find_ignores_case() {
+        let algorithm = find_sort_algorithm::<i32>("tim sort").unwrap();
+        assert_eq!(algorithm.name, "Tim Sort");
+        assert!(algorithm.stable);
+        assert!(algorithm.adaptive);
+        assert_eq!(algorithm.worst_case, "O(n log n)");
+        assert!(find_sort_algorithm::<i32>("Stooge Sort").is_none());
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod selection_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::selection::{bottom_k, bottom_k_tracked, partial_sort, partial_sort_by, partial_sort_by_key, partial_sort_tracked, select_nth, select_nth_by, select_nth_by_key, select_nth_tracked, top_k, top_k_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    fn assert_selected(arr: &[i32], nth: usize, sorted: &[i32]) {
+        assert_eq!(arr[nth], sorted[nth]);
+        assert!(arr[..nth].iter().all(|value| *value <= arr[nth]));
+        assert!(arr[nth + 1..].iter().all(|value| *value >= arr[nth]));
+    }
+
+    #[test]
+    fn test_select_nth_small_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        select_nth(&mut arr, 4);
+        assert_eq!(arr[4], 33);
+    }
+
+    #[test]
+    fn test_select_nth_single_element() {
+        let mut arr = [1];
+        select_nth(&mut arr, 0);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_select_nth_out_of_bounds() {
+        let mut arr: [i32; 0] = [];
+        select_nth(&mut arr, 0);
+    }
+
+    #[test]
+    fn test_select_nth_large_inputs() {
+        for arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4), vec![7; 5000]] {
+            let mut sorted = arr.clone();
+            sorted.sort();
+            for nth in [0, 1, 17, 2500, 4998, 4999] {
+                let mut selected = arr.clone();
+                select_nth(&mut selected, nth);
+                assert_selected(&selected, nth, &sorted);
+            }
+        }
+    }
+
+    #[test]
+    fn test_select_nth_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        select_nth_by(&mut arr, 0, |a, b| b.cmp(a));
+        assert_eq!(arr[0], 78);
+    }
+
+    #[test]
+    fn test_select_nth_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        select_nth_by_key(&mut arr, 1, |&(_, rank)| rank);
+        assert_eq!(arr[1], ("cherry", 2));
+    }
+
+    #[test]
+    fn test_select_nth_tracked_is_linear_on_duplicates() {
+        for mut arr in [vec![7; 20_000], generate_few_unique_vec(20_000, 3)] {
+            let mut tracker = SortTracker::new();
+            select_nth_tracked(&mut arr, 10_000, &mut tracker);
+            assert!(tracker.get_comparison_count() < 40 * 20_000);
+        }
+    }
+
+    #[test]
+    fn test_select_nth_tracked_beats_full_sort() {
+        let arr = generate_random_vec(20_000);
+
+        let mut select_tracker = SortTracker::new();
+        select_nth_tracked(&mut arr.clone(), 10_000, &mut select_tracker);
+
+        let mut sort_tracker = SortTracker::new();
+        partial_sort_tracked(&mut arr.clone(), 20_000, &mut sort_tracker);
+
+        assert!(select_tracker.get_comparison_count() * 4 < sort_tracker.get_comparison_count());
+    }
+
+    #[test]
+    fn test_partial_sort_prefix() {
+        for arr in [generate_random_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4)] {
+            let mut sorted = arr.clone();
+            sorted.sort();
+            for k in [0, 1, 10, 4999, 5000, 6000] {
+                let mut partially_sorted = arr.clone();
+                partial_sort(&mut partially_sorted, k);
+                let k = k.min(5000);
+                assert_eq!(partially_sorted[..k], sorted[..k]);
+
+                let mut rest = partially_sorted[k..].to_vec();
+                rest.sort();
+                assert_eq!(rest, sorted[k..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_partial_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        partial_sort_by(&mut arr, 3, |a, b| b.cmp(a));
+        assert_eq!(arr[..3], [78, 70, 64]);
+    }
+
+    #[test]
+    fn test_partial_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        partial_sort_by_key(&mut arr, 2, |&(_, rank)| rank);
+        assert_eq!(arr[..2], [("apple", 1), ("cherry", 2)]);
+    }
+
+    #[test]
+    fn test_top_k_and_bottom_k() {
+        let values = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        assert_eq!(top_k(values.clone(), 3), vec![78, 70, 64]);
+        assert_eq!(bottom_k(values.clone(), 3), vec![11, 18, 27]);
+        assert_eq!(top_k(values.clone(), 0), Vec::<i32>::new());
+        assert_eq!(bottom_k(values.iter().copied(), 20).len(), 9);
+        assert_eq!(top_k(Vec::<i32>::new(), 3), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_top_k_large_input() {
+        let values = generate_random_vec(5000);
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        let mut expected_top: Vec<i32> = sorted[4900..].to_vec();
+        expected_top.reverse();
+        assert_eq!(top_k(values.iter().copied(), 100), expected_top);
+        assert_eq!(bottom_k(values.iter().copied(), 100), sorted[..100]);
+    }
+
+    #[test]
+    fn test_top_k_tracked_counts_heap_comparisons() {
+        let mut tracker = SortTracker::new();
+        let largest = top_k_tracked(generate_sorted_vec(1000), 10, &mut tracker);
+        assert_eq!(largest, (990..1000).rev().collect::<Vec<i32>>());
+        assert!(tracker.get_comparison_count() > 990);
+        assert!(tracker.get_comparison_count() < 1000 * 10);
+
+        let mut tracker = SortTracker::new();
+        bottom_k_tracked(generate_sorted_vec(1000), 10, &mut tracker);
+        assert!(tracker.get_comparison_count() < 1000 * 2);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod shell_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::insertion_sort::insertion_sort_tracked;
+    use crate::sorting::shell_sort::{shell_sort, shell_sort_by, shell_sort_by_key, shell_sort_tracked, GapSequence};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    const SEQUENCES: [GapSequence; 5] = [GapSequence::Shell, GapSequence::Knuth, GapSequence::Sedgewick, GapSequence::Ciura, GapSequence::Tokuda];
+
+    #[test]
+    fn test_gap_sequences() {
+        assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+        assert_eq!(GapSequence::Knuth.gaps(200), vec![121, 40, 13, 4, 1]);
+        assert_eq!(GapSequence::Sedgewick.gaps(300), vec![281, 77, 23, 8, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(5000), vec![3937, 1750, 701, 301, 132, 57, 23, 10, 4, 1]);
+        assert_eq!(GapSequence::Tokuda.gaps(250), vec![233, 103, 46, 20, 9, 4, 1]);
+    }
+
+    #[test]
+    fn test_gap_sequences_for_tiny_inputs() {
+        for sequence in SEQUENCES {
+            assert!(sequence.gaps(0).is_empty());
+            assert!(sequence.gaps(1).is_empty());
+            assert_eq!(sequence.gaps(2), vec![1]);
+        }
+    }
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        for sequence in SEQUENCES {
+            let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+            shell_sort(&mut arr, sequence);
+            assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+        }
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        shell_sort(&mut arr, GapSequence::Ciura);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        shell_sort(&mut arr, GapSequence::Ciura);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for sequence in SEQUENCES {
+            for mut arr in [generate_random_vec(5000), generate_sorted_vec(5000), generate_reverse_sorted_vec(5000), generate_few_unique_vec(5000, 4)] {
+                let mut expected = arr.clone();
+                expected.sort();
+                shell_sort(&mut arr, sequence);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        shell_sort_by(&mut arr, GapSequence::Knuth, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_field() {
+        let mut arr = vec![("banana", 3), ("apple", 1), ("date", 4), ("cherry", 2)];
+        shell_sort_by_key(&mut arr, GapSequence::Tokuda, |&(_, rank)| rank);
+        assert_eq!(arr, vec![("apple", 1), ("cherry", 2), ("banana", 3), ("date", 4)]);
+    }
+
+    #[test]
+    fn test_tracked_counts_beat_insertion_sort() {
+        let arr = generate_reverse_sorted_vec(2000);
+
+        let mut insertion_tracker = SortTracker::new();
+        insertion_sort_tracked(&mut arr.clone(), &mut insertion_tracker);
+
+        for sequence in SEQUENCES {
+            let mut tracker = SortTracker::new();
+            shell_sort_tracked(&mut arr.clone(), sequence, &mut tracker);
+            assert!(tracker.get_comparison_count() * 20 < insertion_tracker.get_comparison_count());
+        }
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod sort_trace_tests {
+    use std::cmp::Ordering;
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::sort_trace::SortEvent;
+    use crate::sorting::{bottom_up_merge_sort, bubble_sort, bucket_sort, cocktail_shaker_sort, counting_sort, cycle_sort, dual_pivot_quick_sort, gnome_sort, heap_sort, insertion_sort, introsort, merge_sort, natural_merge_sort, odd_even_sort, parallel_merge_sort, parallel_quick_sort, quick_sort, quick_sort_3way, quick_sort_mid, quick_sort_mod, radix_sort, selection, selection_sort, shell_sort, tim_sort};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_random_vec};
+
+    type TrackedSort = fn(&mut [i32], &mut SortTracker);
+
+    fn traced_sorts() -> Vec<(&'static str, TrackedSort)> {
+        vec![
+            ("insertion_sort", insertion_sort::insertion_sort_tracked),
+            ("shell_sort", |arr, tracker| shell_sort::shell_sort_tracked(arr, shell_sort::GapSequence::Ciura, tracker)),
+            ("selection_sort", selection_sort::selection_sort_tracked),
+            ("bubble_sort", bubble_sort::bubble_sort_tracked),
+            ("cocktail_shaker_sort", cocktail_shaker_sort::cocktail_shaker_sort_tracked),
+            ("gnome_sort", gnome_sort::gnome_sort_tracked),
+            ("odd_even_sort", odd_even_sort::odd_even_sort_tracked),
+            ("cycle_sort", cycle_sort::cycle_sort_tracked),
+            ("merge_sort", merge_sort::merge_sort_tracked),
+            ("natural_merge_sort", natural_merge_sort::natural_merge_sort_tracked),
+            ("tim_sort", tim_sort::tim_sort_tracked),
+            ("parallel_merge_sort", |arr, tracker| parallel_merge_sort::parallel_merge_sort_tracked(arr, 4, 32, tracker)),
+            ("heap_sort", heap_sort::heap_sort_tracked),
+            ("quick_sort", quick_sort::quick_sort_tracked),
+            ("quick_sort_mid", quick_sort_mid::quick_sort_tracked),
+            ("quick_sort_mod", |arr, tracker| quick_sort_mod::quick_sort_tracked(arr, 10, tracker)),
+            ("quick_sort_3way", quick_sort_3way::quick_sort_tracked),
+            ("dual_pivot_quick_sort", |arr, tracker| dual_pivot_quick_sort::dual_pivot_quick_sort_tracked(arr, 10, tracker)),
+            ("parallel_quick_sort", |arr, tracker| parallel_quick_sort::parallel_quick_sort_tracked(arr, 10, 4, 32, tracker)),
+            ("introsort", introsort::introsort_tracked),
+            ("partial_sort", |arr, tracker| { let k = arr.len(); selection::partial_sort_tracked(arr, k, tracker) }),
+            ("counting_sort", counting_sort::counting_sort_tracked),
+            ("lsd_radix_sort", radix_sort::lsd_radix_sort_tracked),
+            ("bucket_sort", bucket_sort::bucket_sort_tracked),
+        ]
+    }
+
+    // Sorts that move elements through a buffer, whose traces record their
+    // comparisons but cannot be replayed.
+    fn buffered_sorts() -> Vec<(&'static str, TrackedSort)> {
+        vec![
+            ("bottom_up_merge_sort", bottom_up_merge_sort::bottom_up_merge_sort_tracked),
+        ]
+    }
+
+    #[test]
+    fn test_replay_reproduces_every_sort() {
+        for initial in [generate_random_vec(300), generate_few_unique_vec(300, 5)] {
+            let mut expected = initial.clone();
+            expected.sort();
+
+            for (name, sort) in traced_sorts() {
+                let mut arr = initial.clone();
+                let mut tracker = SortTracker::with_trace();
+                sort(&mut arr, &mut tracker);
+                assert_eq!(arr, expected, "{}", name);
+
+                let trace = tracker.get_trace().unwrap();
+                assert_eq!(trace.replay(&initial, trace.len()), arr, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_replay_reproduces_selection() {
+        let initial = generate_random_vec(1000);
+        let mut arr = initial.clone();
+        let mut tracker = SortTracker::with_trace();
+        selection::select_nth_tracked(&mut arr, 500, &mut tracker);
+        assert_eq!(tracker.get_trace().unwrap().replay(&initial, usize::MAX), arr);
+    }
+
+    #[test]
+    fn test_trace_matches_counts() {
+        for (name, sort) in traced_sorts().into_iter().chain(buffered_sorts()) {
+            let mut arr = generate_random_vec(200);
+            let mut tracker = SortTracker::with_trace();
+            sort(&mut arr, &mut tracker);
+
+            let trace = tracker.get_trace().unwrap();
+            let compares = trace.iter().filter(|event| matches!(event, SortEvent::Compare { .. })).count();
+            let swaps = trace.iter().filter(|event| matches!(event, SortEvent::Swap { .. })).count();
+            let writes = trace.iter().filter(|event| matches!(event, SortEvent::Write { .. })).count();
+            assert_eq!(compares, tracker.get_comparison_count(), "{}", name);
+            assert_eq!(swaps, tracker.get_swap_count(), "{}", name);
+            assert_eq!(writes, tracker.get_write_count(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_insertion_sort_events() {
+        let mut arr = vec![2, 3, 1];
+        let mut tracker = SortTracker::with_trace();
+        insertion_sort::insertion_sort_tracked(&mut arr, &mut tracker);
+
+        let events: Vec<SortEvent> = tracker.get_trace().unwrap().iter().copied().collect();
+        assert_eq!(events, vec![
+            SortEvent::Compare { index1: 1, index2: 0, result: Ordering::Greater },
+            SortEvent::Compare { index1: 2, index2: 1, result: Ordering::Less },
+            SortEvent::Swap { index1: 2, index2: 1 },
+            SortEvent::Compare { index1: 1, index2: 0, result: Ordering::Less },
+            SortEvent::Swap { index1: 1, index2: 0 },
+        ]);
+    }
+
+    #[test]
+    fn test_merge_sort_records_moves_not_swaps() {
+        let mut arr = vec![4, 3, 2, 1];
+        let mut tracker = SortTracker::with_trace();
+        merge_sort::merge_sort_tracked(&mut arr, &mut tracker);
+
+        let trace = tracker.get_trace().unwrap();
+        assert_eq!(tracker.get_swap_count(), 0);
+        assert!(trace.iter().any(|event| matches!(event, SortEvent::Move { .. })));
+        assert_eq!(trace.replay(&[4, 3, 2, 1], trace.len()), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_bottom_up_merge_sort_records_only_comparisons() {
+        let mut arr = vec![4, 3, 2, 1];
+        let mut tracker = SortTracker::with_trace();
+        bottom_up_merge_sort::bottom_up_merge_sort_tracked(&mut arr, &mut tracker);
+
+        let trace = tracker.get_trace().unwrap();
+        assert_eq!(arr, vec![1, 2, 3, 4]);
+        assert_eq!(trace.len(), tracker.get_comparison_count());
+        assert!(trace.iter().all(|event| matches!(event, SortEvent::Compare { .. })));
+    }
+
+    #[test]
+    fn test_replay_steps_forward_and_back() {
+        let initial = vec![3, 1, 2];
+        let mut arr = initial.clone();
+        let mut tracker = SortTracker::with_trace();
+        bubble_sort::bubble_sort_tracked(&mut arr, &mut tracker);
+        let trace = tracker.get_trace().unwrap();
+
+        let mut replay = trace.replayer(&initial);
+        assert_eq!(replay.state(), [3, 1, 2]);
+        assert_eq!(replay.step_back(), None);
+        assert_eq!(replay.step_forward(), Some(SortEvent::Compare { index1: 0, index2: 1, result: Ordering::Greater }));
+        assert_eq!(replay.step_forward(), Some(SortEvent::Swap { index1: 0, index2: 1 }));
+        assert_eq!(replay.state(), [1, 3, 2]);
+
+        replay.seek(trace.len());
+        assert_eq!(replay.state(), [1, 2, 3]);
+        assert_eq!(replay.step_forward(), None);
+
+        replay.seek(1);
+        assert_eq!(replay.position(), 1);
+        assert_eq!(replay.state(), [3, 1, 2]);
+        assert_eq!(trace.replay(&initial, 2), vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_cycle_sort_write_events() {
+        let mut arr = vec![2, 3, 1];
+        let mut tracker = SortTracker::with_trace();
+        cycle_sort::cycle_sort_tracked(&mut arr, &mut tracker);
+
+        let writes: Vec<SortEvent> = tracker.get_trace().unwrap().iter().copied().filter(|event| matches!(event, SortEvent::Write { .. })).collect();
+        assert_eq!(writes, vec![SortEvent::Write { index: 1 }, SortEvent::Write { index: 2 }, SortEvent::Write { index: 0 }]);
+    }
+
+    #[test]
+    fn test_trace_is_opt_in_and_cleared_by_reset() {
+        let mut tracker = SortTracker::new();
+        insertion_sort::insertion_sort_tracked(&mut [2, 1], &mut tracker);
+        assert!(tracker.get_trace().is_none());
+
+        let mut tracker = SortTracker::with_trace();
+        insertion_sort::insertion_sort_tracked(&mut [2, 1], &mut tracker);
+        assert_eq!(tracker.get_trace().unwrap().len(), 2);
+        tracker.reset();
+        assert!(tracker.get_trace().unwrap().is_empty());
+    }
+}
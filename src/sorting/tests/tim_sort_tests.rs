@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tim_sort_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::merge_sort::merge_sort_tracked;
+    use crate::sorting::natural_merge_sort::natural_merge_sort_tracked;
+    use crate::sorting::tim_sort::{tim_sort, tim_sort_by, tim_sort_by_key, tim_sort_tracked};
+    use crate::util::data_generation::{generate_few_unique_vec, generate_nearly_sorted_vec, generate_random_vec, generate_reverse_sorted_vec, generate_sorted_vec};
+
+    #[test]
+    fn test_sort_unsorted_array() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        tim_sort(&mut arr);
+        assert_eq!(arr, vec![11, 18, 27, 32, 33, 45, 64, 70, 78]);
+    }
+
+    #[test]
+    fn test_sort_ascending_array() {
+        let mut arr = vec![10, 20, 30, 40, 50, 60, 70, 80, 90];
+        tim_sort(&mut arr);
+        assert_eq!(arr, vec![10, 20, 30, 40, 50, 60, 70, 80, 90]);
+    }
+
+    #[test]
+    fn test_sort_descending_array() {
+        let mut arr = vec![99, 88, 77, 66, 55, 44, 33, 22, 11];
+        tim_sort(&mut arr);
+        assert_eq!(arr, vec![11, 22, 33, 44, 55, 66, 77, 88, 99]);
+    }
+
+    #[test]
+    fn test_sort_single_element() {
+        let mut arr = [1];
+        tim_sort(&mut arr);
+        assert_eq!(arr, [1]);
+    }
+
+    #[test]
+    fn test_sort_empty_array() {
+        let mut arr: [i32; 0] = [];
+        tim_sort(&mut arr);
+        assert_eq!(arr, []);
+    }
+
+    #[test]
+    fn test_sort_unsorted_strings() {
+        let mut arr = vec!["banana", "apple", "cherry", "date"];
+        tim_sort(&mut arr);
+        assert_eq!(arr, vec!["apple", "banana", "cherry", "date"]);
+    }
+
+    #[test]
+    fn test_sort_large_inputs() {
+        for size in [63, 64, 65, 1000, 5000] {
+            for mut arr in [generate_random_vec(size), generate_sorted_vec(size), generate_reverse_sorted_vec(size), generate_nearly_sorted_vec(size, 10), generate_few_unique_vec(size, 4)] {
+                let mut expected = arr.clone();
+                expected.sort();
+                tim_sort(&mut arr);
+                assert_eq!(arr, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_descending_comparator() {
+        let mut arr = vec![33, 18, 78, 64, 45, 32, 70, 11, 27];
+        tim_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![78, 70, 64, 45, 33, 32, 27, 18, 11]);
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut arr = vec![("b", 2), ("a", 1), ("c", 2), ("d", 1), ("e", 2)];
+        tim_sort_by_key(&mut arr, |&(_, group)| group);
+        assert_eq!(arr, vec![("a", 1), ("d", 1), ("b", 2), ("c", 2), ("e", 2)]);
+    }
+
+    #[test]
+    fn test_sort_by_key_large_input_is_stable() {
+        let mut arr: Vec<(i32, usize)> = generate_random_vec(5000).into_iter().map(|value| value % 50).zip(0..).collect();
+        let mut expected = arr.clone();
+        expected.sort_by_key(|&(group, _)| group);
+        tim_sort_by_key(&mut arr, |&(group, _)| group);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_sorted_input_uses_linear_comparisons() {
+        let mut arr = generate_sorted_vec(10_000);
+        let mut tracker = SortTracker::new();
+        tim_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, generate_sorted_vec(10_000));
+        assert_eq!(tracker.get_comparison_count(), 9_999);
+        assert_eq!(tracker.get_swap_count(), 0);
+    }
+
+    #[test]
+    fn test_nearly_sorted_input_uses_fewer_comparisons_than_merge_sort() {
+        let input = generate_nearly_sorted_vec(10_000, 20);
+
+        let mut arr = input.clone();
+        let mut tracker = SortTracker::new();
+        tim_sort_tracked(&mut arr, &mut tracker);
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let mut arr = input;
+        let mut merge_tracker = SortTracker::new();
+        merge_sort_tracked(&mut arr, &mut merge_tracker);
+
+        assert!(tracker.get_comparison_count() * 2 < merge_tracker.get_comparison_count());
+    }
+
+    #[test]
+    fn test_galloping_merge_of_interleaved_blocks() {
+        // Two long ascending runs whose values alternate in blocks of 500, so
+        // most of the merge is spent copying whole blocks in galloping mode.
+        let left = (0..10_000).filter(|value| (value / 500) % 2 == 0);
+        let right = (0..10_000).filter(|value| (value / 500) % 2 == 1);
+        let input: Vec<i32> = left.chain(right).collect();
+
+        let mut arr = input.clone();
+        let mut tracker = SortTracker::new();
+        tim_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(arr, generate_sorted_vec(10_000));
+
+        let mut arr = input;
+        let mut natural_tracker = SortTracker::new();
+        natural_merge_sort_tracked(&mut arr, &mut natural_tracker);
+
+        assert!(tracker.get_comparison_count() < 11_000);
+        assert!(natural_tracker.get_comparison_count() > 19_000);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod timing_span_tests {
+    use std::thread;
+    use std::time::Duration;
+    use crate::sorting::heap_sort::heap_sort_tracked;
+    use crate::sorting::introsort::introsort_tracked;
+    use crate::sorting::parallel_quick_sort::parallel_quick_sort_tracked;
+    use crate::sorting::quick_sort_mod::quick_sort_tracked;
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::tracker::{ParallelTracker, Tracker};
+    use crate::util::data_generation::{generate_median_of_three_killer_vec, generate_random_vec};
+
+    fn names(tracker: &SortTracker) -> Vec<&'static str> {
+        tracker.get_spans().unwrap().roots().iter().map(|span| span.name()).collect()
+    }
+
+    #[test]
+    fn test_heap_sort_phases() {
+        let mut arr = generate_random_vec(1000);
+        let mut tracker = SortTracker::with_spans();
+        heap_sort_tracked(&mut arr, &mut tracker);
+
+        assert_eq!(names(&tracker), vec!["heapify", "extract"]);
+        for span in tracker.get_spans().unwrap().roots() {
+            assert_eq!(span.count(), 1);
+            assert!(span.children().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_heap_sort_phase_counts_add_up() {
+        let mut arr = generate_random_vec(1000);
+        let mut tracker = SortTracker::with_spans();
+        heap_sort_tracked(&mut arr, &mut tracker);
+
+        let spans = tracker.get_spans().unwrap();
+        let heapify = spans.find(&["heapify"]).unwrap();
+        let extract = spans.find(&["extract"]).unwrap();
+        assert!(heapify.comparisons() > 0 && extract.comparisons() > heapify.comparisons());
+        assert_eq!(heapify.comparisons() + extract.comparisons(), tracker.get_comparison_count());
+        assert_eq!(heapify.swaps() + extract.swaps(), tracker.get_swap_count());
+    }
+
+    #[test]
+    fn test_introsort_heap_sort_fallback_phases() {
+        let mut arr = generate_median_of_three_killer_vec(1000);
+        let mut tracker = SortTracker::with_spans();
+        introsort_tracked(&mut arr, &mut tracker);
+
+        let spans = tracker.get_spans().unwrap();
+        let fallback = spans.find(&["heap sort fallback"]).unwrap();
+        let heapify = spans.find(&["heap sort fallback", "heapify"]).unwrap();
+        let extract = spans.find(&["heap sort fallback", "extract"]).unwrap();
+        assert_eq!(heapify.count(), fallback.count());
+        assert_eq!(heapify.comparisons() + extract.comparisons(), fallback.comparisons());
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_quick_sort_phases_are_combined_by_name() {
+        let mut arr = generate_random_vec(1000);
+        let mut tracker = SortTracker::with_spans();
+        quick_sort_tracked(&mut arr, 10, &mut tracker);
+
+        assert_eq!(names(&tracker), vec!["partition", "insertion cutoff"]);
+        let partition = tracker.get_spans().unwrap().find(&["partition"]).unwrap();
+        let cutoff = tracker.get_spans().unwrap().find(&["insertion cutoff"]).unwrap();
+        assert!(partition.count() > 1);
+        assert!(cutoff.count() > 1);
+        assert!(partition.duration() + cutoff.duration() <= tracker.get_duration());
+        assert_eq!(partition.comparisons() + cutoff.comparisons(), tracker.get_comparison_count());
+    }
+
+    #[test]
+    fn test_introsort_phases() {
+        let mut arr = generate_random_vec(1000);
+        let mut tracker = SortTracker::with_spans();
+        introsort_tracked(&mut arr, &mut tracker);
+
+        let spans = tracker.get_spans().unwrap();
+        assert!(spans.find(&["partition"]).is_some());
+        assert!(spans.find(&["insertion cutoff"]).is_some());
+    }
+
+    #[test]
+    fn test_spans_nest() {
+        let mut tracker = SortTracker::with_spans();
+        for _ in 0..3 {
+            tracker.enter_span("outer");
+            tracker.enter_span("inner");
+            tracker.exit_span();
+            tracker.enter_span("inner");
+            tracker.exit_span();
+            tracker.exit_span();
+        }
+
+        let outer = tracker.get_spans().unwrap().find(&["outer"]).unwrap();
+        assert_eq!(outer.count(), 3);
+        assert_eq!(outer.children().len(), 1);
+        assert_eq!(tracker.get_spans().unwrap().find(&["outer", "inner"]).unwrap().count(), 6);
+        assert!(tracker.get_spans().unwrap().find(&["inner"]).is_none());
+    }
+
+    #[test]
+    fn test_nested_heap_sort_phases_sit_under_their_caller() {
+        let mut arr = generate_random_vec(100);
+        let mut tracker = SortTracker::with_spans();
+        tracker.enter_span("fallback");
+        heap_sort_tracked(&mut arr, &mut tracker);
+        tracker.exit_span();
+
+        assert_eq!(names(&tracker), vec!["fallback"]);
+        assert_eq!(tracker.get_spans().unwrap().find(&["fallback", "heapify"]).unwrap().count(), 1);
+        assert_eq!(tracker.get_spans().unwrap().find(&["fallback", "extract"]).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_span_measures_elapsed_time() {
+        let mut tracker = SortTracker::with_spans();
+        tracker.enter_span("sleep");
+        thread::sleep(Duration::from_millis(5));
+        tracker.exit_span();
+        assert!(tracker.get_spans().unwrap().find(&["sleep"]).unwrap().duration() >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_unmatched_exit_is_ignored() {
+        let mut tracker = SortTracker::with_spans();
+        tracker.exit_span();
+        assert!(tracker.get_spans().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_display_indents_children() {
+        let mut tracker = SortTracker::with_spans();
+        tracker.enter_span("sort");
+        tracker.enter_span("partition");
+        tracker.exit_span();
+        tracker.exit_span();
+
+        let report = tracker.get_spans().unwrap().to_string();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("sort: "));
+        assert!(lines[0].ends_with("over 1 call, 0 comparisons, 0 swaps"));
+        assert!(lines[1].starts_with("  partition: "));
+    }
+
+    #[test]
+    fn test_reset_clears_spans() {
+        let mut arr = generate_random_vec(100);
+        let mut tracker = SortTracker::with_spans();
+        heap_sort_tracked(&mut arr, &mut tracker);
+        tracker.reset();
+        assert!(tracker.get_spans().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_nested_timers_time_the_outermost_run() {
+        let mut tracker = SortTracker::new();
+        tracker.start_timer();
+        let mut arr = generate_random_vec(100);
+        heap_sort_tracked(&mut arr, &mut tracker);
+        thread::sleep(Duration::from_millis(5));
+        tracker.stop_timer();
+        assert!(tracker.get_duration() >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_joined_worker_spans_merge_under_open_span() {
+        let mut tracker = SortTracker::with_spans();
+        let mut worker = tracker.fork();
+        worker.enter_span("partition");
+        worker.record_comparison();
+        worker.exit_span();
+
+        tracker.enter_span("sort");
+        tracker.join(worker);
+        tracker.exit_span();
+
+        assert_eq!(tracker.get_spans().unwrap().find(&["sort", "partition"]).unwrap().count(), 1);
+        assert_eq!(tracker.get_spans().unwrap().find(&["sort", "partition"]).unwrap().comparisons(), 1);
+        assert_eq!(tracker.get_spans().unwrap().find(&["sort"]).unwrap().comparisons(), 1);
+    }
+
+    #[test]
+    fn test_spans_are_off_by_default() {
+        let mut arr = generate_random_vec(100);
+        let mut tracker = SortTracker::new();
+        quick_sort_tracked(&mut arr, 10, &mut tracker);
+        tracker.enter_span("sort");
+        tracker.exit_span();
+
+        assert!(tracker.get_spans().is_none());
+        assert!(tracker.fork().get_spans().is_none());
+    }
+
+    #[test]
+    fn test_parallel_quick_sort_combines_worker_spans() {
+        let mut arr = generate_random_vec(10_000);
+        let mut tracker = SortTracker::with_spans();
+        parallel_quick_sort_tracked(&mut arr, 10, 4, 64, &mut tracker);
+
+        assert!(arr.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(names(&tracker), vec!["partition", "insertion cutoff"]);
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tracker_tests {
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::sorting::tracker::{NoopTracker, Tracker};
+    use crate::sorting::{heap_sort, insertion_sort, merge_sort, quick_sort, quick_sort_mid, quick_sort_mod};
+
+    #[derive(Default)]
+    struct CountingTracker {
+        comparisons: usize,
+        swaps: usize,
+        timer_starts: usize,
+        timer_stops: usize,
+    }
+
+    impl Tracker for CountingTracker {
+        fn record_comparison(&mut self) {
+            self.comparisons += 1;
+        }
+
+        fn record_swap(&mut self) {
+            self.swaps += 1;
+        }
+
+        fn start_timer(&mut self) {
+            self.timer_starts += 1;
+        }
+
+        fn stop_timer(&mut self) {
+            self.timer_stops += 1;
+        }
+    }
+
+    fn unsorted() -> Vec<i32> {
+        vec![33, 18, 78, 64, 45, 32, 70, 11, 27, 18, 90, 1, 55, 64, 3, 42]
+    }
+
+    #[test]
+    fn test_noop_tracker_matches_untracked() {
+        let mut expected = unsorted();
+        expected.sort();
+
+        let mut arr = unsorted();
+        insertion_sort::insertion_sort_tracked(&mut arr, &mut NoopTracker);
+        assert_eq!(arr, expected);
+
+        let mut arr = unsorted();
+        merge_sort::merge_sort_tracked(&mut arr, &mut NoopTracker);
+        assert_eq!(arr, expected);
+
+        let mut arr = unsorted();
+        heap_sort::heap_sort_tracked(&mut arr, &mut NoopTracker);
+        assert_eq!(arr, expected);
+
+        let mut arr = unsorted();
+        quick_sort::quick_sort_tracked(&mut arr, &mut NoopTracker);
+        assert_eq!(arr, expected);
+
+        let mut arr = unsorted();
+        quick_sort_mid::quick_sort_tracked(&mut arr, &mut NoopTracker);
+        assert_eq!(arr, expected);
+
+        let mut arr = unsorted();
+        quick_sort_mod::quick_sort_tracked(&mut arr, 4, &mut NoopTracker);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_custom_tracker_matches_sort_tracker() {
+        let mut arr = unsorted();
+        let mut sort_tracker = SortTracker::new();
+        quick_sort_mod::quick_sort_tracked(&mut arr, 4, &mut sort_tracker);
+
+        let mut arr = unsorted();
+        let mut counting_tracker = CountingTracker::default();
+        quick_sort_mod::quick_sort_tracked(&mut arr, 4, &mut counting_tracker);
+
+        assert_eq!(counting_tracker.comparisons, sort_tracker.get_comparison_count());
+        assert_eq!(counting_tracker.swaps, sort_tracker.get_swap_count());
+    }
+
+    #[test]
+    fn test_swap_based_sorts_read_and_move_through_the_helpers() {
+        let mut arr = unsorted();
+        let mut tracker = SortTracker::new();
+        heap_sort::heap_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(tracker.get_move_count(), 2 * tracker.get_swap_count());
+        assert_eq!(tracker.get_read_count(), 2 * (tracker.get_comparison_count() + tracker.get_swap_count()));
+        assert_eq!(tracker.get_peak_auxiliary_memory(), 0);
+
+        let mut arr = unsorted();
+        let mut tracker = SortTracker::new();
+        quick_sort::quick_sort_tracked(&mut arr, &mut tracker);
+        assert_eq!(tracker.get_move_count(), 2 * tracker.get_swap_count());
+        assert_eq!(tracker.get_read_count(), 2 * (tracker.get_comparison_count() + tracker.get_swap_count()));
+        assert_eq!(tracker.get_peak_auxiliary_memory(), 0);
+    }
+
+    #[test]
+    fn test_merge_sort_moves_are_comparable_with_swap_based_sorts() {
+        let mut arr = unsorted();
+        let mut merge_tracker = SortTracker::new();
+        merge_sort::merge_sort_tracked(&mut arr, &mut merge_tracker);
+
+        let mut arr = unsorted();
+        let mut heap_tracker = SortTracker::new();
+        heap_sort::heap_sort_tracked(&mut arr, &mut heap_tracker);
+
+        assert_eq!(merge_tracker.get_swap_count(), 0);
+        assert!(merge_tracker.get_move_count() > 0);
+        assert!(merge_tracker.get_move_count() < heap_tracker.get_move_count());
+        assert!(merge_tracker.get_read_count() > 2 * merge_tracker.get_comparison_count());
+    }
+
+    #[test]
+    fn test_reset_clears_read_move_and_memory_counts() {
+        let mut arr = unsorted();
+        let mut tracker = SortTracker::new();
+        merge_sort::merge_sort_tracked(&mut arr, &mut tracker);
+        tracker.reset();
+        assert_eq!(tracker.get_read_count(), 0);
+        assert_eq!(tracker.get_move_count(), 0);
+        assert_eq!(tracker.get_auxiliary_memory(), 0);
+        assert_eq!(tracker.get_peak_auxiliary_memory(), 0);
+    }
+
+    #[test]
+    fn test_nested_sorts_time_a_single_run() {
+        let mut arr = unsorted();
+        let mut tracker = CountingTracker::default();
+        quick_sort_mod::quick_sort_tracked(&mut arr, 4, &mut tracker);
+        assert_eq!(tracker.timer_starts, 1);
+        assert_eq!(tracker.timer_stops, 1);
+
+        let mut arr: Vec<i32> = Vec::new();
+        let mut tracker = CountingTracker::default();
+        quick_sort_mod::quick_sort_tracked(&mut arr, 4, &mut tracker);
+        assert_eq!(tracker.timer_starts, 1);
+        assert_eq!(tracker.timer_stops, 1);
+    }
+
+    #[test]
+    fn test_sort_tracker_default_and_reexported_trait() {
+        let mut arr = vec![3, 1, 2];
+        let mut tracker = SortTracker::default();
+        crate::sorting::sort_tracker::Tracker::swap(&mut tracker, &mut arr, 0, 1);
+        assert_eq!(arr, vec![1, 3, 2]);
+        assert_eq!(tracker.get_swap_count(), 1);
+        assert_eq!(tracker.get_comparison_count(), 0);
+    }
+
+    // Callers that only import `SortTracker` can still use its helpers.
+    mod without_tracker_trait {
+        use crate::sorting::sort_tracker::SortTracker;
+
+        #[test]
+        fn test_inherent_helpers_forward_to_tracker() {
+            let mut arr = vec![3, 1, 2];
+            let mut tracker = SortTracker::new();
+            assert!(tracker.compare_gt(&arr, 0, 1));
+            assert!(tracker.compare_gte(&arr, 0, 0));
+            assert!(tracker.compare_lt(&arr, 1, 2));
+            assert!(!tracker.compare_lte(&arr, 0, 2));
+            tracker.swap(&mut arr, 0, 1);
+            assert_eq!(arr, vec![1, 3, 2]);
+            assert_eq!(tracker.get_comparison_count(), 4);
+            assert_eq!(tracker.get_swap_count(), 1);
+        }
+    }
+}
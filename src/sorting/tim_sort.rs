@@ -0,0 +1,282 @@
+use std::cmp::Ordering;
+use crate::sorting::compare::partial_compare;
+use crate::sorting::merge_sort::apply_order;
+use crate::sorting::natural_merge_sort::count_run;
+use crate::sorting::tracker::{NoopTracker, Tracker};
+
+// Slices shorter than this are sorted with a single binary insertion sort.
+const MIN_MERGE: usize = 64;
+
+// Number of consecutive wins by one run before a merge switches to galloping.
+const MIN_GALLOP: usize = 7;
+
+pub fn tim_sort<T: PartialOrd>(arr: &mut [T]) {
+    tim_sort_tracked(arr, &mut NoopTracker);
+}
+
+pub fn tim_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    tim_sort_by_tracked(arr, compare, &mut NoopTracker);
+}
+
+pub fn tim_sort_by_key<T, B: PartialOrd, F: FnMut(&T) -> B>(arr: &mut [T], key: F) {
+    tim_sort_by_key_tracked(arr, key, &mut NoopTracker);
+}
+
+pub fn tim_sort_tracked<T: PartialOrd, K: Tracker>(arr: &mut [T], tracker: &mut K) {
+    tim_sort_by_tracked(arr, partial_compare, tracker);
+}
+
+pub fn tim_sort_by_key_tracked<T, B: PartialOrd, F: FnMut(&T) -> B, K: Tracker>(arr: &mut [T], mut key: F, tracker: &mut K) {
+    tim_sort_by_tracked(arr, |a, b| partial_compare(&key(a), &key(b)), tracker);
+}
+
+pub fn tim_sort_by_tracked<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], mut compare: F, tracker: &mut K) {
+    tracker.start_timer();
+
+    let mut state = TimSortState {
+        runs: Vec::new(),
+        min_gallop: MIN_GALLOP,
+    };
+
+    let min_run = min_run_length(arr.len());
+    let mut run_start: usize = 0;
+
+    while run_start < arr.len() {
+        tracker.enter_span("count run");
+        let mut run_length = count_run(arr, run_start, arr.len(), &mut compare, tracker);
+        tracker.exit_span();
+
+        // Extend short runs to `min_run` so the merges stay balanced.
+        if run_length < min_run {
+            let forced_length = min_run.min(arr.len() - run_start);
+            tracker.enter_span("extend run");
+            binary_insertion_sort(&mut arr[run_start..run_start + forced_length], run_length, &mut compare, &mut tracker.offset(run_start));
+            tracker.exit_span();
+            run_length = forced_length;
+        }
+
+        state.runs.push(Run { start: run_start, length: run_length });
+        tracker.enter_span("merge");
+        state.merge_collapse(arr, &mut compare, tracker);
+        tracker.exit_span();
+
+        run_start += run_length;
+    }
+
+    tracker.enter_span("merge");
+    state.merge_force_collapse(arr, &mut compare, tracker);
+    tracker.exit_span();
+
+    tracker.stop_timer();
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    length: usize,
+}
+
+struct TimSortState {
+    runs: Vec<Run>,
+    min_gallop: usize,
+}
+
+impl TimSortState {
+    /// Merges runs until the stack invariants hold again:
+    /// `runs[i - 2].length > runs[i - 1].length + runs[i].length` and
+    /// `runs[i - 1].length > runs[i].length` for the top of the stack.
+    fn merge_collapse<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(&mut self, arr: &mut [T], compare: &mut F, tracker: &mut K) {
+        while self.runs.len() > 1 {
+            let mut n = self.runs.len() - 2;
+            let runs = &self.runs;
+
+            if (n > 0 && runs[n - 1].length <= runs[n].length + runs[n + 1].length)
+                || (n > 1 && runs[n - 2].length <= runs[n - 1].length + runs[n].length)
+            {
+                if runs[n - 1].length < runs[n + 1].length {
+                    n -= 1;
+                }
+            } else if runs[n].length > runs[n + 1].length {
+                break;
+            }
+
+            self.merge_at(n, arr, compare, tracker);
+        }
+    }
+
+    /// Merges every remaining run once the whole input has been scanned.
+    fn merge_force_collapse<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(&mut self, arr: &mut [T], compare: &mut F, tracker: &mut K) {
+        while self.runs.len() > 1 {
+            let mut n = self.runs.len() - 2;
+            if n > 0 && self.runs[n - 1].length < self.runs[n + 1].length {
+                n -= 1;
+            }
+            self.merge_at(n, arr, compare, tracker);
+        }
+    }
+
+    /// Merges the runs at stack positions `i` and `i + 1`.
+    fn merge_at<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(&mut self, i: usize, arr: &mut [T], compare: &mut F, tracker: &mut K) {
+        let left = self.runs[i];
+        let right = self.runs.remove(i + 1);
+        self.runs[i].length = left.length + right.length;
+
+        // Elements of the left run that are <= the first element of the right
+        // run, and elements of the right run that are >= the last element of
+        // the left run, are already in their final positions.
+        let skipped = gallop(arr, right.start, left.start, left.length, true, compare, tracker);
+        let left_start = left.start + skipped;
+        if left_start == right.start {
+            return;
+        }
+        let right_end = right.start + gallop(arr, right.start - 1, right.start, right.length, false, compare, tracker);
+
+        self.merge_runs(arr, left_start, right.start, right_end, compare, tracker);
+    }
+
+    /// Merges `arr[left_start..right_start]` with `arr[right_start..right_end]`,
+    /// switching to galloping mode when one run keeps winning.
+    fn merge_runs<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(&mut self, arr: &mut [T], left_start: usize, right_start: usize, right_end: usize, compare: &mut F, tracker: &mut K) {
+        let mut merged_order: Vec<usize> = Vec::with_capacity(right_end - left_start);
+        tracker.record_auxiliary_memory((right_end - left_start) * size_of::<usize>());
+        let mut left_position: usize = left_start;
+        let mut right_position: usize = right_start;
+        let mut left_wins: usize = 0;
+        let mut right_wins: usize = 0;
+
+        while left_position < right_start && right_position < right_end {
+            if tracker.compare_by(arr, right_position, left_position, compare) == Ordering::Less {
+                merged_order.push(right_position - left_start);
+                right_position += 1;
+                right_wins += 1;
+                left_wins = 0;
+            } else {
+                merged_order.push(left_position - left_start);
+                left_position += 1;
+                left_wins += 1;
+                right_wins = 0;
+            }
+
+            if left_wins < self.min_gallop && right_wins < self.min_gallop {
+                continue;
+            }
+
+            // Galloping mode: copy whole blocks found by exponential search
+            // for as long as the blocks stay long enough to pay for it.
+            while left_position < right_start && right_position < right_end {
+                let left_block = gallop(arr, right_position, left_position, right_start - left_position, true, compare, tracker);
+                merged_order.extend((left_position..left_position + left_block).map(|position| position - left_start));
+                left_position += left_block;
+                if left_position == right_start {
+                    break;
+                }
+
+                merged_order.push(right_position - left_start);
+                right_position += 1;
+                if right_position == right_end {
+                    break;
+                }
+
+                let right_block = gallop(arr, left_position, right_position, right_end - right_position, false, compare, tracker);
+                merged_order.extend((right_position..right_position + right_block).map(|position| position - left_start));
+                right_position += right_block;
+                if right_position == right_end {
+                    break;
+                }
+
+                merged_order.push(left_position - left_start);
+                left_position += 1;
+
+                self.min_gallop = self.min_gallop.saturating_sub(1).max(1);
+                if left_block < MIN_GALLOP && right_block < MIN_GALLOP {
+                    break;
+                }
+            }
+
+            // Leaving galloping mode is penalised so that random data goes
+            // back to one-at-a-time merging quickly.
+            self.min_gallop += 1;
+            left_wins = 0;
+            right_wins = 0;
+        }
+
+        merged_order.extend((left_position..right_start).map(|position| position - left_start));
+        merged_order.extend((right_position..right_end).map(|position| position - left_start));
+
+        apply_order(&mut arr[left_start..right_end], &mut merged_order, &mut tracker.offset(left_start));
+        tracker.record_auxiliary_memory_freed((right_end - left_start) * size_of::<usize>());
+    }
+}
+
+// Returns how many leading elements of `arr[base..base + length]` belong before
+// `arr[key]`: those `<=` it when `after_equal` is set, those `<` it otherwise.
+// An exponential search brackets the answer before a binary search finds it,
+// so a result of `k` costs O(log k) comparisons.
+fn gallop<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &[T], key: usize, base: usize, length: usize, after_equal: bool, compare: &mut F, tracker: &mut K) -> usize {
+    let mut belongs_before = |index: usize, tracker: &mut K| {
+        match tracker.compare_by(arr, index, key, compare) {
+            Ordering::Less => true,
+            Ordering::Equal => after_equal,
+            Ordering::Greater => false,
+        }
+    };
+
+    if length == 0 || !belongs_before(base, tracker) {
+        return 0;
+    }
+
+    let mut last_before: usize = 0;
+    let mut offset: usize = 1;
+    while offset < length && belongs_before(base + offset, tracker) {
+        last_before = offset;
+        offset = offset * 2 + 1;
+    }
+
+    let mut low = last_before + 1;
+    let mut high = offset.min(length);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if belongs_before(base + mid, tracker) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+// Sorts `arr`, whose first `sorted_length` elements are already in order, by
+// binary searching each new element's position and shifting it into place.
+fn binary_insertion_sort<T, F: FnMut(&T, &T) -> Ordering, K: Tracker>(arr: &mut [T], sorted_length: usize, compare: &mut F, tracker: &mut K) {
+    for i in sorted_length.max(1)..arr.len() {
+        let mut low: usize = 0;
+        let mut high: usize = i;
+
+        // Insert after any equal elements to keep the sort stable.
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if tracker.compare_by(arr, i, mid, compare) == Ordering::Less {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        for j in (low..i).rev() {
+            tracker.swap(arr, j, j + 1);
+        }
+    }
+}
+
+// Computes the minimum run length: `n` itself for short inputs, otherwise a
+// value in `[MIN_MERGE / 2, MIN_MERGE]` such that `n / min_run` is a power of
+// two or slightly less than one.
+fn min_run_length(mut n: usize) -> usize {
+    let mut low_bits_set: usize = 0;
+    while n >= MIN_MERGE {
+        low_bits_set |= n & 1;
+        n >>= 1;
+    }
+    n + low_bits_set
+}
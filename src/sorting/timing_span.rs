@@ -0,0 +1,183 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The combined timing and operation counts of every run of one named phase
+/// of a sort, such as "partition" or "heapify", at one position in the span
+/// tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimingSpan {
+    name: &'static str,
+    duration: Duration,
+    count: usize,
+    comparisons: usize,
+    swaps: usize,
+    children: Vec<TimingSpan>,
+}
+
+/// The operation counts of a tracker when a span is entered or exited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct SpanCounts {
+    pub(crate) comparisons: usize,
+    pub(crate) swaps: usize,
+}
+
+impl TimingSpan {
+    fn new(name: &'static str) -> Self {
+        TimingSpan { name, duration: Duration::new(0, 0), count: 0, comparisons: 0, swaps: 0, children: Vec::new() }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The total time spent in the phase, including its children.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The number of times the phase was entered.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The comparisons made in the phase, including its children.
+    pub fn comparisons(&self) -> usize {
+        self.comparisons
+    }
+
+    /// The swaps made in the phase, including its children.
+    pub fn swaps(&self) -> usize {
+        self.swaps
+    }
+
+    /// The phases entered while this one was open, in the order they were
+    /// first entered.
+    pub fn children(&self) -> &[TimingSpan] {
+        &self.children
+    }
+
+    pub fn child(&self, name: &str) -> Option<&TimingSpan> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn merge(&mut self, other: TimingSpan) {
+        self.duration += other.duration;
+        self.count += other.count;
+        self.comparisons += other.comparisons;
+        self.swaps += other.swaps;
+        merge_spans(&mut self.children, other.children);
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let calls = if self.count == 1 { "call" } else { "calls" };
+        writeln!(f, "{:indent$}{}: {:?} over {} {}, {} comparisons, {} swaps", "", self.name, self.duration, self.count, calls, self.comparisons, self.swaps, indent = 2 * depth)?;
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Nested timing spans recorded by a `SortTracker`.
+///
+/// A span entered while another is open becomes its child, and spans with the
+/// same name under the same parent are combined, so a phase run thousands of
+/// times shows up once with its total time, count and the comparisons and
+/// swaps made inside it. `Display` prints the tree with children indented
+/// under their parents.
+#[derive(Debug, Clone, Default)]
+pub struct SpanTree {
+    roots: Vec<TimingSpan>,
+    // The path of child indices to each open span, with when it was entered
+    // and the tracker's counts at that point.
+    open: Vec<(usize, Instant, SpanCounts)>,
+}
+
+impl SpanTree {
+    pub fn new() -> Self {
+        SpanTree { roots: Vec::new(), open: Vec::new() }
+    }
+
+    /// The outermost spans, in the order they were first entered.
+    pub fn roots(&self) -> &[TimingSpan] {
+        &self.roots
+    }
+
+    /// Finds a span by its path of names from the root, such as
+    /// `["heap sort fallback", "heapify"]` for the heap sort introsort falls
+    /// back to.
+    pub fn find(&self, path: &[&str]) -> Option<&TimingSpan> {
+        let (first, rest) = path.split_first()?;
+        let mut span = self.roots.iter().find(|root| root.name == *first)?;
+        for name in rest {
+            span = span.child(name)?;
+        }
+        Some(span)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    pub(crate) fn enter(&mut self, name: &'static str, counts: SpanCounts) {
+        let siblings = self.open_children();
+        let index = match siblings.iter().position(|span| span.name == name) {
+            Some(index) => index,
+            None => {
+                siblings.push(TimingSpan::new(name));
+                siblings.len() - 1
+            }
+        };
+        self.open.push((index, Instant::now(), counts));
+    }
+
+    pub(crate) fn exit(&mut self, counts: SpanCounts) {
+        let Some((index, start, entered)) = self.open.pop() else {
+            return;
+        };
+        let span = &mut self.open_children()[index];
+        span.duration += start.elapsed();
+        span.count += 1;
+        span.comparisons += counts.comparisons - entered.comparisons;
+        span.swaps += counts.swaps - entered.swaps;
+    }
+
+    /// Adds the spans of a finished worker under the span open here. Their
+    /// durations are summed, so a phase run on several threads reports the
+    /// total time across threads.
+    pub(crate) fn append(&mut self, other: SpanTree) {
+        merge_spans(self.open_children(), other.roots);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.roots.clear();
+        self.open.clear();
+    }
+
+    // The children of the innermost open span, or the roots if none is open.
+    fn open_children(&mut self) -> &mut Vec<TimingSpan> {
+        let mut children = &mut self.roots;
+        for &(index, _, _) in &self.open {
+            children = &mut children[index].children;
+        }
+        children
+    }
+}
+
+impl fmt::Display for SpanTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for root in &self.roots {
+            root.fmt_indented(f, 0)?;
+        }
+        Ok(())
+    }
+}
+
+fn merge_spans(spans: &mut Vec<TimingSpan>, others: Vec<TimingSpan>) {
+    for other in others {
+        match spans.iter_mut().find(|span| span.name == other.name) {
+            Some(span) => span.merge(other),
+            None => spans.push(other),
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use std::cmp::{Ordering, PartialOrd};
+use crate::sorting::sort_trace::SortEvent;
+
+/// Instrumentation hooks shared by every sorting algorithm.
+///
+/// Each algorithm is written once against this trait. `SortTracker` records
+/// comparisons, swaps, reads, moves, memory and timing, while `NoopTracker`
+/// compiles down to the plain, uninstrumented algorithm.
+pub trait Tracker {
+    /// Records that two elements were compared.
+    fn record_comparison(&mut self);
+
+    /// Records that two elements were swapped.
+    fn record_swap(&mut self);
+
+    /// Records that an element, or a key derived from one, was read from the
+    /// array being sorted. Ignored by default.
+    #[inline]
+    fn record_read(&mut self) {}
+
+    /// Records that an element was moved to another position in the array,
+    /// whether by a counted swap or while applying a computed order. Ignored
+    /// by default.
+    #[inline]
+    fn record_move(&mut self) {}
+
+    /// Records that the sort allocated `bytes` of auxiliary memory. Trackers
+    /// that do not report memory can rely on the default, which ignores it.
+    #[inline]
+    fn record_auxiliary_memory(&mut self, bytes: usize) {
+        let _ = bytes;
+    }
+
+    /// Records that the sort released `bytes` of auxiliary memory it had
+    /// reported through `record_auxiliary_memory`. Ignored by default.
+    #[inline]
+    fn record_auxiliary_memory_freed(&mut self, bytes: usize) {
+        let _ = bytes;
+    }
+
+    /// Records that the element at `index` was written into its final
+    /// position. Only sorts that minimise writes, such as cycle sort, report
+    /// this. Ignored by default.
+    #[inline]
+    fn record_write(&mut self, index: usize) {
+        let _ = index;
+    }
+
+    /// Records one distribution pass of a non-comparison sort over the input
+    /// or one of its buckets. Ignored by default.
+    #[inline]
+    fn record_pass(&mut self) {}
+
+    /// Records that a non-comparison sort wrote an element into a bucket.
+    /// Ignored by default.
+    #[inline]
+    fn record_bucket_write(&mut self) {}
+
+    /// Records a step of the sort, with its indices, for trackers that keep a
+    /// trace. Counting is done by the other `record_*` methods, so this is
+    /// ignored by default.
+    #[inline]
+    fn record_event(&mut self, event: SortEvent) {
+        let _ = event;
+    }
+
+    /// Opens a timing span for a named phase of the sort, such as
+    /// "partition". Spans nest, and every call must be matched by
+    /// `exit_span`. Ignored by default.
+    #[inline]
+    fn enter_span(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Closes the span most recently opened by `enter_span`. Ignored by
+    /// default.
+    #[inline]
+    fn exit_span(&mut self) {}
+
+    /// Starts timing a sort run. Calls may nest, in which case only the
+    /// outermost pair is timed.
+    fn start_timer(&mut self);
+
+    /// Stops timing a sort run.
+    fn stop_timer(&mut self);
+
+    /// Returns `true` if `array[index1] > array[index2]`.
+    #[inline]
+    fn compare_gt<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
+        let ordering = self.compare_partial(array, index1, index2);
+        ordering == Some(Ordering::Greater)
+    }
+
+    /// Returns `true` if `array[index1] >= array[index2]`.
+    #[inline]
+    fn compare_gte<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
+        let ordering = self.compare_partial(array, index1, index2);
+        matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+    }
+
+    /// Returns `true` if `array[index1] < array[index2]`.
+    #[inline]
+    fn compare_lt<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
+        let ordering = self.compare_partial(array, index1, index2);
+        ordering == Some(Ordering::Less)
+    }
+
+    /// Returns `true` if `array[index1] <= array[index2]`.
+    #[inline]
+    fn compare_lte<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> bool {
+        let ordering = self.compare_partial(array, index1, index2);
+        matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+    }
+
+    /// Compares `array[index1]` with `array[index2]` using `PartialOrd`,
+    /// returning `None` for incomparable values.
+    #[inline]
+    fn compare_partial<T: PartialOrd>(&mut self, array: &[T], index1: usize, index2: usize) -> Option<Ordering> {
+        self.record_comparison();
+        self.record_read();
+        self.record_read();
+        let ordering = array[index1].partial_cmp(&array[index2]);
+        self.record_event(SortEvent::Compare { index1, index2, result: ordering.unwrap_or(Ordering::Equal) });
+        ordering
+    }
+
+    /// Compares `array[index1]` with `array[index2]` using `compare`.
+    #[inline]
+    fn compare_by<T, F>(&mut self, array: &[T], index1: usize, index2: usize, compare: &mut F) -> Ordering
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.record_comparison();
+        self.record_read();
+        self.record_read();
+        let result = compare(&array[index1], &array[index2]);
+        self.record_event(SortEvent::Compare { index1, index2, result });
+        result
+    }
+
+    /// Swaps `array[index1]` and `array[index2]`.
+    #[inline]
+    fn swap<T>(&mut self, array: &mut [T], index1: usize, index2: usize) {
+        self.record_swap();
+        self.record_read();
+        self.record_read();
+        self.record_move();
+        self.record_move();
+        self.record_event(SortEvent::Swap { index1, index2 });
+        array.swap(index1, index2);
+    }
+
+    /// Returns a tracker that forwards to this one with every event index
+    /// shifted by `offset`. Sorts pass it along when they hand a subslice
+    /// starting at `offset` to another sort, so traced indices stay relative
+    /// to the whole array.
+    #[inline]
+    fn offset(&mut self, offset: usize) -> OffsetTracker<'_, Self>
+    where
+        Self: Sized,
+    {
+        OffsetTracker { inner: self, offset }
+    }
+}
+
+/// A tracker for a subslice, created by `Tracker::offset`.
+pub struct OffsetTracker<'a, K: Tracker> {
+    inner: &'a mut K,
+    offset: usize,
+}
+
+impl<K: Tracker> Tracker for OffsetTracker<'_, K> {
+    #[inline]
+    fn record_comparison(&mut self) {
+        self.inner.record_comparison();
+    }
+
+    #[inline]
+    fn record_swap(&mut self) {
+        self.inner.record_swap();
+    }
+
+    #[inline]
+    fn record_read(&mut self) {
+        self.inner.record_read();
+    }
+
+    #[inline]
+    fn record_move(&mut self) {
+        self.inner.record_move();
+    }
+
+    #[inline]
+    fn record_auxiliary_memory(&mut self, bytes: usize) {
+        self.inner.record_auxiliary_memory(bytes);
+    }
+
+    #[inline]
+    fn record_auxiliary_memory_freed(&mut self, bytes: usize) {
+        self.inner.record_auxiliary_memory_freed(bytes);
+    }
+
+    #[inline]
+    fn record_write(&mut self, index: usize) {
+        self.inner.record_write(index + self.offset);
+    }
+
+    #[inline]
+    fn record_pass(&mut self) {
+        self.inner.record_pass();
+    }
+
+    #[inline]
+    fn record_bucket_write(&mut self) {
+        self.inner.record_bucket_write();
+    }
+
+    #[inline]
+    fn record_event(&mut self, event: SortEvent) {
+        self.inner.record_event(event.shifted(self.offset));
+    }
+
+    #[inline]
+    fn enter_span(&mut self, name: &'static str) {
+        self.inner.enter_span(name);
+    }
+
+    #[inline]
+    fn exit_span(&mut self) {
+        self.inner.exit_span();
+    }
+
+    #[inline]
+    fn start_timer(&mut self) {
+        self.inner.start_timer();
+    }
+
+    #[inline]
+    fn stop_timer(&mut self) {
+        self.inner.stop_timer();
+    }
+}
+
+/// A tracker that can be split across worker threads by the parallel sorts.
+///
+/// Each worker records into its own fork, and the forks are joined back into
+/// the parent once the worker finishes, so no counter is shared between
+/// threads.
+pub trait ParallelTracker: Tracker + Send {
+    /// Creates an empty tracker for a worker thread.
+    fn fork(&self) -> Self;
+
+    /// Adds everything recorded by a finished worker into this tracker.
+    fn join(&mut self, worker: Self);
+}
+
+/// A tracker that records nothing, used by the untracked sort functions.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopTracker;
+
+impl Tracker for NoopTracker {
+    #[inline(always)]
+    fn record_comparison(&mut self) {}
+
+    #[inline(always)]
+    fn record_swap(&mut self) {}
+
+    #[inline(always)]
+    fn start_timer(&mut self) {}
+
+    #[inline(always)]
+    fn stop_timer(&mut self) {}
+}
+
+impl ParallelTracker for NoopTracker {
+    #[inline(always)]
+    fn fork(&self) -> Self {
+        NoopTracker
+    }
+
+    #[inline(always)]
+    fn join(&mut self, _worker: Self) {}
+}
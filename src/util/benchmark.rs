@@ -0,0 +1,99 @@
+use std::time::Instant;
+use crate::sorting::registry::SortAlgorithm;
+use crate::sorting::sort_tracker::SortTracker;
+use crate::util::data_generation::{DataGenerator, Distribution};
+use crate::util::reporting::BenchmarkRecord;
+
+/// Times registered sorts on generated inputs.
+///
+/// Each measurement first sorts `warmup` inputs without recording them, to
+/// settle caches and the allocator, then sorts `iterations` inputs and
+/// summarises them as a `BenchmarkRecord`. Every run gets a freshly generated
+/// input, and only the sort itself is timed. The timed runs call the untracked
+/// `sort`, so that counting does not inflate the durations, and the counts
+/// come from a separate `sort_tracked` run on a copy of the same input.
+/// Inputs are drawn from a `DataGenerator` seeded with `seed` at the start of
+/// each measurement, so every algorithm sees the same inputs and a run can be
+/// repeated exactly.
+#[derive(Debug, Clone)]
+pub struct BenchmarkRunner {
+    warmup: usize,
+    iterations: usize,
+    seed: u64,
+}
+
+impl Default for BenchmarkRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BenchmarkRunner {
+    /// Creates a runner with 3 warmup runs, 30 timed iterations and a random
+    /// seed.
+    pub fn new() -> Self {
+        BenchmarkRunner { warmup: 3, iterations: 30, seed: rand::random() }
+    }
+
+    pub fn warmup(mut self, runs: usize) -> Self {
+        self.warmup = runs;
+        self
+    }
+
+    /// Sets the number of timed runs. Values below 1 are treated as 1.
+    pub fn iterations(mut self, runs: usize) -> Self {
+        self.iterations = runs.max(1);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn run(&self, algorithm: &SortAlgorithm<i32>, distribution: &Distribution, size: usize) -> BenchmarkRecord {
+        let mut generator = DataGenerator::new(self.seed);
+        for _ in 0..self.warmup {
+            let mut arr = (distribution.generate)(&mut generator, size);
+            (algorithm.sort)(&mut arr);
+        }
+
+        let mut runs = Vec::with_capacity(self.iterations);
+        let mut durations = Vec::with_capacity(self.iterations);
+        for _ in 0..self.iterations {
+            let input = (distribution.generate)(&mut generator, size);
+
+            let mut arr = input.clone();
+            let start = Instant::now();
+            (algorithm.sort)(&mut arr);
+            durations.push(start.elapsed());
+
+            let mut arr = input;
+            let mut tracker = SortTracker::new();
+            (algorithm.sort_tracked)(&mut arr, &mut tracker);
+            runs.push(tracker);
+        }
+
+        BenchmarkRecord::from_runs(algorithm.name, distribution.name, size, &runs, &durations)
+    }
+
+    /// Runs every algorithm on every distribution and size, calling `progress`
+    /// with each record as it is finished.
+    pub fn run_all<F: FnMut(&BenchmarkRecord)>(&self, algorithms: &[SortAlgorithm<i32>], distributions: &[Distribution], sizes: &[usize], mut progress: F) -> Vec<BenchmarkRecord> {
+        let mut records = Vec::with_capacity(algorithms.len() * distributions.len() * sizes.len());
+        for distribution in distributions {
+            for algorithm in algorithms {
+                for &size in sizes {
+                    let record = self.run(algorithm, distribution, size);
+                    progress(&record);
+                    records.push(record);
+                }
+            }
+        }
+        records
+    }
+}
@@ -1,13 +1,127 @@
+use std::f64::consts::PI;
+use std::ops::Range;
 use rand::prelude::*;
+use rand::rngs::StdRng;
 use rand::Rng;
 
-pub fn generate_random_vec(size: usize) -> Vec<i32> {
-    let mut rng = thread_rng();
-    let mut vec = Vec::with_capacity(size);
-    for _ in 0..size {
-        vec.push(rng.gen_range(0..100_000));
+/// A seeded source of random inputs.
+///
+/// Two generators created with the same seed produce the same sequence of
+/// inputs, so a failing test or a benchmark run can be reproduced from its
+/// seed. The free `generate_*` functions below that take no generator draw
+/// from a fresh, unseeded one on every call.
+#[derive(Debug, Clone)]
+pub struct DataGenerator {
+    rng: StdRng,
+}
+
+impl DataGenerator {
+    pub fn new(seed: u64) -> Self {
+        DataGenerator { rng: StdRng::seed_from_u64(seed) }
+    }
+
+    /// Creates a generator seeded by the operating system, for inputs that
+    /// should differ between runs.
+    pub fn from_entropy() -> Self {
+        DataGenerator { rng: StdRng::from_os_rng() }
+    }
+
+    /// Returns `size` values produced by `value`, for element types without a
+    /// dedicated generator.
+    pub fn vec_with<T, F: FnMut(&mut StdRng) -> T>(&mut self, size: usize, mut value: F) -> Vec<T> {
+        (0..size).map(|_| value(&mut self.rng)).collect()
+    }
+
+    /// Returns `size` values drawn uniformly from `range`.
+    pub fn random_vec(&mut self, size: usize, range: Range<i32>) -> Vec<i32> {
+        self.vec_with(size, |rng| rng.random_range(range.clone()))
+    }
+
+    /// Returns `0..size` in order with `swaps` random pairs of positions
+    /// swapped.
+    pub fn nearly_sorted_vec(&mut self, size: usize, swaps: usize) -> Vec<i32> {
+        let mut vec = generate_sorted_vec(size);
+        if size > 1 {
+            for _ in 0..swaps {
+                let i = self.rng.random_range(0..size);
+                let j = self.rng.random_range(0..size);
+                vec.swap(i, j);
+            }
+        }
+        vec
+    }
+
+    /// Returns `size` values drawn uniformly from `0..distinct_values`.
+    pub fn few_unique_vec(&mut self, size: usize, distinct_values: usize) -> Vec<i32> {
+        self.random_vec(size, 0..distinct_values.max(1) as i32)
+    }
+
+    /// Returns `size` values from `1..=distinct_values` where value `k` is
+    /// drawn with probability proportional to `1 / k^exponent`, so a few small
+    /// values make up most of the input.
+    pub fn zipf_vec(&mut self, size: usize, distinct_values: usize, exponent: f64) -> Vec<i32> {
+        let mut cumulative: Vec<f64> = Vec::with_capacity(distinct_values.max(1));
+        let mut total = 0.0;
+        for k in 1..=distinct_values.max(1) {
+            total += 1.0 / (k as f64).powf(exponent);
+            cumulative.push(total);
+        }
+        self.vec_with(size, |rng| {
+            let target = rng.random::<f64>() * total;
+            let index = cumulative.partition_point(|&weight| weight <= target);
+            index.min(cumulative.len() - 1) as i32 + 1
+        })
+    }
+
+    /// Returns `size` values from a normal distribution, rounded to the
+    /// nearest integer.
+    pub fn gaussian_vec(&mut self, size: usize, mean: f64, std_dev: f64) -> Vec<i32> {
+        self.vec_with(size, |rng| {
+            // Box-Muller transform. `1 - u` keeps the logarithm's argument
+            // in (0, 1].
+            let u1: f64 = 1.0 - rng.random::<f64>();
+            let u2: f64 = rng.random();
+            let z = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+            (mean + std_dev * z).round() as i32
+        })
+    }
+
+    /// Returns random values from `0..100_000` arranged in consecutive
+    /// ascending runs of `run_length`, the last of which may be shorter.
+    pub fn sorted_runs_vec(&mut self, size: usize, run_length: usize) -> Vec<i32> {
+        let mut vec = self.random_vec(size, 0..100_000);
+        for run in vec.chunks_mut(run_length.max(1)) {
+            run.sort_unstable();
+        }
+        vec
+    }
+
+    /// Returns `size` values drawn uniformly from `range`.
+    pub fn float_vec(&mut self, size: usize, range: Range<f64>) -> Vec<f64> {
+        self.vec_with(size, |rng| rng.random_range(range.clone()))
+    }
+
+    /// Returns `size` strings of lowercase ASCII letters, each up to
+    /// `max_length` characters long. Short lengths give many duplicates and
+    /// shared prefixes.
+    pub fn string_vec(&mut self, size: usize, max_length: usize) -> Vec<String> {
+        self.vec_with(size, |rng| {
+            let length = rng.random_range(0..=max_length);
+            (0..length).map(|_| rng.random_range(b'a'..=b'z') as char).collect()
+        })
+    }
+
+    /// Returns `(key, payload)` records with keys drawn from
+    /// `0..distinct_keys` and the payload set to each record's position, so
+    /// that a sort by key can be checked for stability by looking at the
+    /// payloads of equal keys.
+    pub fn record_vec(&mut self, size: usize, distinct_keys: usize) -> Vec<(i32, usize)> {
+        self.few_unique_vec(size, distinct_keys).into_iter().zip(0..).collect()
     }
-    vec
+}
+
+pub fn generate_random_vec(size: usize) -> Vec<i32> {
+    DataGenerator::from_entropy().random_vec(size, 0..100_000)
 }
 
 pub fn generate_sorted_vec(size: usize) -> Vec<i32> {
@@ -25,3 +139,75 @@ pub fn generate_reverse_sorted_vec(size: usize) -> Vec<i32> {
     }
     vec
 }
+
+pub fn generate_few_unique_vec(size: usize, distinct_values: usize) -> Vec<i32> {
+    DataGenerator::from_entropy().few_unique_vec(size, distinct_values)
+}
+
+pub fn generate_nearly_sorted_vec(size: usize, swaps: usize) -> Vec<i32> {
+    DataGenerator::from_entropy().nearly_sorted_vec(size, swaps)
+}
+
+/// Returns values that rise to a peak in the middle and fall again, such as
+/// `0, 1, 2, 2, 1, 0`.
+pub fn generate_organ_pipe_vec(size: usize) -> Vec<i32> {
+    (0..size).map(|i| i.min(size - 1 - i) as i32).collect()
+}
+
+/// Returns `0, 1, ..., period - 1` repeated to fill `size` values.
+pub fn generate_sawtooth_vec(size: usize, period: usize) -> Vec<i32> {
+    (0..size).map(|i| (i % period.max(1)) as i32).collect()
+}
+
+pub fn generate_all_equal_vec(size: usize, value: i32) -> Vec<i32> {
+    vec![value; size]
+}
+
+/// Returns Musser's median-of-3 killer sequence, a permutation of `1..=size`
+/// on which quick sorts that take the median of the first, middle and last
+/// elements as pivot split off only two elements per partition and take
+/// quadratic time. The construction needs a multiple of 4, so for other sizes
+/// the remaining largest values are appended in order.
+pub fn generate_median_of_three_killer_vec(size: usize) -> Vec<i32> {
+    let length = size - size % 4;
+    let half = length / 2;
+    let mut vec: Vec<i32> = (1..=size as i32).collect();
+    for i in 1..=half {
+        if i % 2 == 1 {
+            vec[i - 1] = i as i32;
+            vec[i] = (half + i) as i32;
+        }
+        vec[half + i - 1] = (2 * i) as i32;
+    }
+    vec
+}
+
+/// A named input distribution, as chosen on the benchmark command line.
+#[derive(Debug, Clone, Copy)]
+pub struct Distribution {
+    pub name: &'static str,
+    pub generate: fn(&mut DataGenerator, usize) -> Vec<i32>,
+}
+
+/// Returns every distribution the benchmarks can generate inputs from.
+pub fn distributions() -> Vec<Distribution> {
+    vec![
+        Distribution { name: "random", generate: |generator, size| generator.random_vec(size, 0..100_000) },
+        Distribution { name: "sorted", generate: |_, size| generate_sorted_vec(size) },
+        Distribution { name: "reverse sorted", generate: |_, size| generate_reverse_sorted_vec(size) },
+        Distribution { name: "nearly sorted", generate: |generator, size| generator.nearly_sorted_vec(size, size / 100) },
+        Distribution { name: "few unique", generate: |generator, size| generator.few_unique_vec(size, 10) },
+        Distribution { name: "organ pipe", generate: |_, size| generate_organ_pipe_vec(size) },
+        Distribution { name: "sawtooth", generate: |_, size| generate_sawtooth_vec(size, 64) },
+        Distribution { name: "all equal", generate: |_, size| generate_all_equal_vec(size, 0) },
+        Distribution { name: "zipf", generate: |generator, size| generator.zipf_vec(size, 1000, 1.0) },
+        Distribution { name: "gaussian", generate: |generator, size| generator.gaussian_vec(size, 0.0, 1000.0) },
+        Distribution { name: "median-of-3 killer", generate: |_, size| generate_median_of_three_killer_vec(size) },
+        Distribution { name: "sorted runs", generate: |generator, size| generator.sorted_runs_vec(size, 100) },
+    ]
+}
+
+/// Returns the distribution called `name`, ignoring case.
+pub fn find_distribution(name: &str) -> Option<Distribution> {
+    distributions().into_iter().find(|distribution| distribution.name.eq_ignore_ascii_case(name))
+}
@@ -1,7 +1,12 @@
 use plotters::prelude::*;
 use std::error::Error;
+use std::fmt::Write;
 use std::fs;
 use std::path::Path;
+use std::time::Duration;
+use crate::sorting::sort_tracker::SortTracker;
+
+const CSV_HEADER: &str = "algorithm,distribution,size,repetitions,comparisons,swaps,moves,duration_p50_ns,duration_p90_ns,duration_p99_ns,duration_ci_low_ns,duration_ci_high_ns";
 
 pub fn create_plot(data: &[(usize, u128)], filename: &str, title: &str, label: &str, x_desc: &str, y_desc: &str) -> Result<(), Box<dyn Error>> {
     let output_dir = Path::new("target/benchmarks");
@@ -38,3 +43,228 @@ pub fn create_plot(data: &[(usize, u128)], filename: &str, title: &str, label: &
 
     Ok(())
 }
+
+/// Plots the median duration against input size for every algorithm in
+/// `records` that was run on `distribution`, with error bars spanning the
+/// confidence interval of each median. Durations are drawn in microseconds.
+pub fn create_benchmark_plot(records: &[BenchmarkRecord], distribution: &str, filename: &str, title: &str) -> Result<(), Box<dyn Error>> {
+    let output_dir = Path::new("target/benchmarks");
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir)?;
+    }
+
+    let filepath = output_dir.join(filename);
+
+    let root = BitMapBackend::new(filepath.to_str().unwrap(), (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let records: Vec<&BenchmarkRecord> = records.iter().filter(|record| record.distribution == distribution).collect();
+    let mut algorithms: Vec<&str> = Vec::new();
+    for record in &records {
+        if !algorithms.contains(&record.algorithm.as_str()) {
+            algorithms.push(&record.algorithm);
+        }
+    }
+
+    let micros = |duration: Duration| duration.as_secs_f64() * 1e6;
+    let max_x = records.iter().map(|record| record.size).max().unwrap_or(0);
+    let max_y = records.iter().map(|record| micros(record.duration_ci_high)).fold(0.0, f64::max);
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption(title, ("sans-serif", 50).into_font())
+        .margin(10)
+        .x_label_area_size(50)
+        .y_label_area_size(70)
+        .build_cartesian_2d(0..max_x, 0.0..max_y * 1.05)?;
+
+    chart.configure_mesh()
+        .x_desc("Input Size")
+        .y_desc("Median Time (µs)")
+        .draw()?;
+
+    for (index, algorithm) in algorithms.iter().enumerate() {
+        let color = Palette99::pick(index).to_rgba();
+        let mut points: Vec<&BenchmarkRecord> = records.iter().copied().filter(|record| record.algorithm == *algorithm).collect();
+        points.sort_by_key(|record| record.size);
+
+        chart.draw_series(
+            LineSeries::new(points.iter().map(|record| (record.size, micros(record.duration_p50))), color)
+        )?
+            .label(*algorithm)
+            .legend(move |(x, y)| PathElement::new([(x, y), (x + 20, y)], color));
+        chart.draw_series(points.iter().map(|record| {
+            ErrorBar::new_vertical(record.size, micros(record.duration_ci_low), micros(record.duration_p50), micros(record.duration_ci_high), color, 6)
+        }))?;
+    }
+
+    chart.configure_series_labels()
+        .border_style(BLACK)
+        .background_style(WHITE.mix(0.8))
+        .draw()?;
+
+    Ok(())
+}
+
+/// The results of running one algorithm `repetitions` times on inputs of one
+/// size and distribution, as written by `write_benchmark_records`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchmarkRecord {
+    pub algorithm: String,
+    pub distribution: String,
+    pub size: usize,
+    pub repetitions: usize,
+    /// Mean comparisons per run.
+    pub comparisons: usize,
+    /// Mean swaps per run.
+    pub swaps: usize,
+    /// Mean moves per run, see `SortTracker::get_move_count`.
+    pub moves: usize,
+    pub duration_p50: Duration,
+    pub duration_p90: Duration,
+    pub duration_p99: Duration,
+    /// Bounds of a distribution-free 95% confidence interval for the median
+    /// duration. With only a few runs they are the fastest and slowest.
+    pub duration_ci_low: Duration,
+    pub duration_ci_high: Duration,
+}
+
+impl BenchmarkRecord {
+    /// Summarises a measurement from the trackers of the counting runs and the
+    /// wall-clock durations of the timed runs, which are taken separately so
+    /// that the tracker's bookkeeping is not part of the time. Counts are
+    /// averaged over `runs`, durations are nearest-rank percentiles and
+    /// `repetitions` is the number of durations.
+    pub fn from_runs(algorithm: &str, distribution: &str, size: usize, runs: &[SortTracker], durations: &[Duration]) -> Self {
+        let repetitions = durations.len();
+        let mean = |count: fn(&SortTracker) -> usize| runs.iter().map(count).sum::<usize>() / runs.len().max(1);
+
+        let mut durations = durations.to_vec();
+        durations.sort();
+        let percentile = |p: usize| match durations.len() {
+            0 => Duration::new(0, 0),
+            len => durations[(p * len).div_ceil(100).max(1) - 1],
+        };
+
+        // The median lies between the order statistics at ranks
+        // n/2 -/+ 1.96 * sqrt(n)/2 with 95% probability, whatever the
+        // distribution of the durations.
+        let half_width = 0.98 * (repetitions as f64).sqrt();
+        let rank = |r: f64| (r.round() as usize).clamp(1, repetitions.max(1)) - 1;
+        let order_statistic = |r: f64| durations.get(rank(r)).copied().unwrap_or_default();
+
+        BenchmarkRecord {
+            algorithm: algorithm.to_string(),
+            distribution: distribution.to_string(),
+            size,
+            repetitions,
+            comparisons: mean(SortTracker::get_comparison_count),
+            swaps: mean(SortTracker::get_swap_count),
+            moves: mean(SortTracker::get_move_count),
+            duration_p50: percentile(50),
+            duration_p90: percentile(90),
+            duration_p99: percentile(99),
+            duration_ci_low: order_statistic(repetitions as f64 / 2.0 - half_width),
+            duration_ci_high: order_statistic(repetitions as f64 / 2.0 + 1.0 + half_width),
+        }
+    }
+}
+
+/// Formats records as a JSON array of objects. Durations are written in
+/// nanoseconds under keys ending in `_ns`.
+pub fn benchmark_records_to_json(records: &[BenchmarkRecord]) -> String {
+    let mut json = String::from("[");
+    for (index, record) in records.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        write!(
+            json,
+            "\n  {{\"algorithm\": {}, \"distribution\": {}, \"size\": {}, \"repetitions\": {}, \"comparisons\": {}, \"swaps\": {}, \"moves\": {}, \"duration_p50_ns\": {}, \"duration_p90_ns\": {}, \"duration_p99_ns\": {}, \"duration_ci_low_ns\": {}, \"duration_ci_high_ns\": {}}}",
+            json_string(&record.algorithm),
+            json_string(&record.distribution),
+            record.size,
+            record.repetitions,
+            record.comparisons,
+            record.swaps,
+            record.moves,
+            record.duration_p50.as_nanos(),
+            record.duration_p90.as_nanos(),
+            record.duration_p99.as_nanos(),
+            record.duration_ci_low.as_nanos(),
+            record.duration_ci_high.as_nanos(),
+        ).unwrap();
+    }
+    if !records.is_empty() {
+        json.push('\n');
+    }
+    json.push_str("]\n");
+    json
+}
+
+/// Formats records as CSV with a header row, using the same field names as
+/// `benchmark_records_to_json`.
+pub fn benchmark_records_to_csv(records: &[BenchmarkRecord]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for record in records {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            csv_field(&record.algorithm),
+            csv_field(&record.distribution),
+            record.size,
+            record.repetitions,
+            record.comparisons,
+            record.swaps,
+            record.moves,
+            record.duration_p50.as_nanos(),
+            record.duration_p90.as_nanos(),
+            record.duration_p99.as_nanos(),
+            record.duration_ci_low.as_nanos(),
+            record.duration_ci_high.as_nanos(),
+        ).unwrap();
+    }
+    csv
+}
+
+/// Writes `<name>.json` and `<name>.csv` next to the plots in
+/// `target/benchmarks`.
+pub fn write_benchmark_records(records: &[BenchmarkRecord], name: &str) -> Result<(), Box<dyn Error>> {
+    let output_dir = Path::new("target/benchmarks");
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir)?;
+    }
+
+    fs::write(output_dir.join(format!("{}.json", name)), benchmark_records_to_json(records))?;
+    fs::write(output_dir.join(format!("{}.csv", name)), benchmark_records_to_csv(records))?;
+
+    Ok(())
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// Quotes a field that contains a delimiter, quote or line break, doubling any
+// quotes inside it.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod benchmark_tests {
+    use std::fs;
+    use crate::sorting::registry::{find_sort_algorithm, sort_algorithms};
+    use crate::util::benchmark::BenchmarkRunner;
+    use crate::util::data_generation::{distributions, find_distribution, DataGenerator};
+    use crate::util::reporting::create_benchmark_plot;
+
+    #[test]
+    fn test_run_records_every_iteration() {
+        let algorithm = find_sort_algorithm("Merge Sort").unwrap();
+        let distribution = find_distribution("random").unwrap();
+
+        let record = BenchmarkRunner::new().warmup(1).iterations(12).run(&algorithm, &distribution, 200);
+
+        assert_eq!(record.algorithm, "Merge Sort");
+        assert_eq!(record.distribution, "random");
+        assert_eq!(record.size, 200);
+        assert_eq!(record.repetitions, 12);
+        assert!(record.comparisons > 0);
+        assert!(record.duration_ci_low <= record.duration_p50);
+        assert!(record.duration_p50 <= record.duration_ci_high);
+    }
+
+    #[test]
+    fn test_iterations_are_at_least_one() {
+        let algorithm = find_sort_algorithm("Heap Sort").unwrap();
+        let distribution = find_distribution("sorted").unwrap();
+
+        let record = BenchmarkRunner::new().warmup(0).iterations(0).run(&algorithm, &distribution, 10);
+        assert_eq!(record.repetitions, 1);
+    }
+
+    #[test]
+    fn test_run_all_covers_every_combination() {
+        let algorithms: Vec<_> = sort_algorithms().into_iter().filter(|algorithm| algorithm.name.contains("Quick Sort")).collect();
+        let distributions = distributions();
+        let sizes = [10, 100];
+        let mut progress = 0;
+
+        let records = BenchmarkRunner::new().warmup(0).iterations(3).run_all(&algorithms, &distributions, &sizes, |_| progress += 1);
+
+        assert_eq!(records.len(), algorithms.len() * distributions.len() * sizes.len());
+        assert_eq!(progress, records.len());
+        for algorithm in &algorithms {
+            for distribution in &distributions {
+                for size in sizes {
+                    assert!(records.iter().any(|record| record.algorithm == algorithm.name && record.distribution == distribution.name && record.size == size));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_distribution_ignores_case() {
+        assert_eq!(find_distribution("Reverse Sorted").unwrap().name, "reverse sorted");
+        assert!(find_distribution("pareto").is_none());
+        assert_eq!((find_distribution("few unique").unwrap().generate)(&mut DataGenerator::new(1), 50).len(), 50);
+    }
+
+    #[test]
+    fn test_create_benchmark_plot() {
+        let algorithms = [find_sort_algorithm("Merge Sort").unwrap(), find_sort_algorithm("Tim Sort").unwrap()];
+        let distributions = [find_distribution("random").unwrap(), find_distribution("sorted").unwrap()];
+        let records = BenchmarkRunner::new().warmup(0).iterations(5).run_all(&algorithms, &distributions, &[100, 200], |_| {});
+
+        let filename = format!("benchmark_tests_{}.png", std::process::id());
+        create_benchmark_plot(&records, "sorted", &filename, "Sorted input").unwrap();
+
+        let path = format!("target/benchmarks/{}", filename);
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        fs::remove_file(path).unwrap();
+    }
+}
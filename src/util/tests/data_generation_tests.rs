@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod data_generation_tests {
+    use std::collections::HashSet;
+    use crate::sorting::quick_sort_mod::quick_sort_tracked;
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::util::data_generation::*;
+
+    fn is_permutation_of_one_to(vec: &[i32], size: usize) -> bool {
+        let mut sorted = vec.to_vec();
+        sorted.sort();
+        sorted == (1..=size as i32).collect::<Vec<i32>>()
+    }
+
+    #[test]
+    fn test_same_seed_gives_same_inputs() {
+        let mut first = DataGenerator::new(42);
+        let mut second = DataGenerator::new(42);
+        assert_eq!(first.random_vec(100, 0..1000), second.random_vec(100, 0..1000));
+        assert_eq!(first.gaussian_vec(100, 0.0, 10.0), second.gaussian_vec(100, 0.0, 10.0));
+        assert_eq!(first.string_vec(100, 5), second.string_vec(100, 5));
+
+        let mut other = DataGenerator::new(43);
+        assert_ne!(DataGenerator::new(42).random_vec(100, 0..1000), other.random_vec(100, 0..1000));
+    }
+
+    #[test]
+    fn test_every_distribution_is_reproducible() {
+        for distribution in distributions() {
+            let first = (distribution.generate)(&mut DataGenerator::new(7), 1000);
+            let second = (distribution.generate)(&mut DataGenerator::new(7), 1000);
+            assert_eq!(first.len(), 1000, "{}", distribution.name);
+            assert_eq!(first, second, "{}", distribution.name);
+        }
+    }
+
+    #[test]
+    fn test_random_vec_stays_in_range() {
+        let vec = DataGenerator::new(1).random_vec(1000, -5..5);
+        assert!(vec.iter().all(|value| (-5..5).contains(value)));
+    }
+
+    #[test]
+    fn test_nearly_sorted_vec() {
+        let vec = DataGenerator::new(1).nearly_sorted_vec(1000, 5);
+        let out_of_place = vec.iter().enumerate().filter(|&(i, &value)| value != i as i32).count();
+        assert!(out_of_place <= 10);
+        assert!(is_permutation_of_one_to(&vec.iter().map(|value| value + 1).collect::<Vec<i32>>(), 1000));
+    }
+
+    #[test]
+    fn test_few_unique_vec() {
+        let vec = DataGenerator::new(1).few_unique_vec(1000, 4);
+        let distinct: HashSet<i32> = vec.into_iter().collect();
+        assert_eq!(distinct, HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_zipf_vec_favours_small_values() {
+        let vec = DataGenerator::new(1).zipf_vec(10_000, 100, 1.0);
+        assert!(vec.iter().all(|value| (1..=100).contains(value)));
+        let ones = vec.iter().filter(|&&value| value == 1).count();
+        let hundreds = vec.iter().filter(|&&value| value == 100).count();
+        assert!(ones > 10 * hundreds.max(1));
+    }
+
+    #[test]
+    fn test_gaussian_vec_mean_and_spread() {
+        let vec = DataGenerator::new(1).gaussian_vec(10_000, 500.0, 100.0);
+        let mean = vec.iter().map(|&value| value as f64).sum::<f64>() / vec.len() as f64;
+        let within_one_std_dev = vec.iter().filter(|&&value| (400..=600).contains(&value)).count();
+        assert!((mean - 500.0).abs() < 5.0);
+        assert!((6500..=7100).contains(&within_one_std_dev));
+    }
+
+    #[test]
+    fn test_sorted_runs_vec() {
+        let vec = DataGenerator::new(1).sorted_runs_vec(1050, 100);
+        for run in vec.chunks(100) {
+            assert!(run.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+        assert!(!vec.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_float_string_and_record_vecs() {
+        let mut generator = DataGenerator::new(1);
+        assert!(generator.float_vec(1000, -1.0..1.0).iter().all(|value| (-1.0..1.0).contains(value)));
+
+        let strings = generator.string_vec(1000, 3);
+        assert!(strings.iter().all(|s| s.len() <= 3 && s.bytes().all(|b| b.is_ascii_lowercase())));
+
+        let records = generator.record_vec(1000, 10);
+        assert!(records.iter().enumerate().all(|(i, &(key, payload))| payload == i && (0..10).contains(&key)));
+    }
+
+    #[test]
+    fn test_vec_with() {
+        let pairs = DataGenerator::new(1).vec_with(10, |rng| {
+            use rand::Rng;
+            (rng.random_range(0..3u8), rng.random::<bool>())
+        });
+        assert_eq!(pairs.len(), 10);
+        assert!(pairs.iter().all(|&(value, _)| value < 3));
+    }
+
+    #[test]
+    fn test_deterministic_shapes() {
+        assert_eq!(generate_organ_pipe_vec(6), vec![0, 1, 2, 2, 1, 0]);
+        assert_eq!(generate_organ_pipe_vec(5), vec![0, 1, 2, 1, 0]);
+        assert_eq!(generate_sawtooth_vec(7, 3), vec![0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!(generate_all_equal_vec(3, 9), vec![9, 9, 9]);
+        assert!(generate_organ_pipe_vec(0).is_empty());
+    }
+
+    #[test]
+    fn test_median_of_three_killer_is_a_permutation() {
+        for size in [0, 1, 2, 7, 10, 11, 1000] {
+            assert!(is_permutation_of_one_to(&generate_median_of_three_killer_vec(size), size), "{}", size);
+        }
+    }
+
+    #[test]
+    fn test_median_of_three_killer_is_quadratic() {
+        let comparisons = |vec: &mut Vec<i32>| {
+            let mut tracker = SortTracker::new();
+            quick_sort_tracked(vec, 1, &mut tracker);
+            tracker.get_comparison_count()
+        };
+
+        let random = comparisons(&mut DataGenerator::new(1).random_vec(4000, 0..100_000));
+        for size in [4000, 4001, 4002, 4003] {
+            let killer = comparisons(&mut generate_median_of_three_killer_vec(size));
+            assert!(killer > 10 * random, "{}: {} vs {}", size, killer, random);
+        }
+    }
+
+    #[test]
+    fn test_find_distribution() {
+        for name in ["Zipf", "gaussian", "Median-of-3 Killer", "sorted runs", "organ pipe", "sawtooth", "all equal"] {
+            assert!(find_distribution(name).is_some(), "{}", name);
+        }
+    }
+}
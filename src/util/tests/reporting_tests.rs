@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod reporting_tests {
+    use std::fs;
+    use std::time::Duration;
+    use crate::sorting::heap_sort::heap_sort_tracked;
+    use crate::sorting::sort_tracker::SortTracker;
+    use crate::util::reporting::{benchmark_records_to_csv, benchmark_records_to_json, write_benchmark_records, BenchmarkRecord};
+
+    fn record(algorithm: &str) -> BenchmarkRecord {
+        BenchmarkRecord {
+            algorithm: algorithm.to_string(),
+            distribution: "random".to_string(),
+            size: 1000,
+            repetitions: 5,
+            comparisons: 8700,
+            swaps: 9100,
+            moves: 18200,
+            duration_p50: Duration::from_nanos(1500),
+            duration_p90: Duration::from_nanos(2500),
+            duration_p99: Duration::from_nanos(3000),
+            duration_ci_low: Duration::from_nanos(1400),
+            duration_ci_high: Duration::from_nanos(1700),
+        }
+    }
+
+    #[test]
+    fn test_from_runs_averages_counts() {
+        let mut runs = Vec::new();
+        for size in [10, 20] {
+            let mut arr: Vec<i32> = (0..size).rev().collect();
+            let mut tracker = SortTracker::new();
+            heap_sort_tracked(&mut arr, &mut tracker);
+            runs.push(tracker);
+        }
+
+        let durations = [Duration::from_nanos(300), Duration::from_nanos(100)];
+
+        let record = BenchmarkRecord::from_runs("Heap Sort", "reverse sorted", 20, &runs, &durations);
+
+        assert_eq!(record.algorithm, "Heap Sort");
+        assert_eq!(record.distribution, "reverse sorted");
+        assert_eq!(record.repetitions, 2);
+        assert_eq!(record.comparisons, (runs[0].get_comparison_count() + runs[1].get_comparison_count()) / 2);
+        assert_eq!(record.swaps, (runs[0].get_swap_count() + runs[1].get_swap_count()) / 2);
+        assert_eq!(record.moves, (runs[0].get_move_count() + runs[1].get_move_count()) / 2);
+    }
+
+    #[test]
+    fn test_from_runs_nearest_rank_percentiles() {
+        let durations: Vec<Duration> = (1..=10).rev().map(Duration::from_micros).collect();
+
+        let record = BenchmarkRecord::from_runs("Heap Sort", "reverse sorted", 100, &[], &durations);
+
+        assert_eq!(record.repetitions, 10);
+        assert_eq!(record.comparisons, 0);
+        assert_eq!(record.duration_p50, Duration::from_micros(5));
+        assert_eq!(record.duration_p90, Duration::from_micros(9));
+        assert_eq!(record.duration_p99, Duration::from_micros(10));
+        assert_eq!(record.duration_ci_low, Duration::from_micros(2));
+        assert_eq!(record.duration_ci_high, Duration::from_micros(9));
+    }
+
+    #[test]
+    fn test_from_runs_median_confidence_interval() {
+        let durations: Vec<Duration> = (1..=100).rev().map(Duration::from_micros).collect();
+
+        let record = BenchmarkRecord::from_runs("Heap Sort", "reverse sorted", 100, &[], &durations);
+
+        // Ranks 50 -/+ 9.8, rounded, counted from 1.
+        assert_eq!(record.duration_ci_low, Duration::from_micros(40));
+        assert_eq!(record.duration_ci_high, Duration::from_micros(61));
+        assert!(record.duration_ci_low <= record.duration_p50 && record.duration_p50 <= record.duration_ci_high);
+    }
+
+    #[test]
+    fn test_from_no_runs() {
+        let record = BenchmarkRecord::from_runs("Heap Sort", "random", 0, &[], &[]);
+        assert_eq!(record.repetitions, 0);
+        assert_eq!(record.comparisons, 0);
+        assert_eq!(record.duration_p99, Duration::new(0, 0));
+        assert_eq!(record.duration_ci_high, Duration::new(0, 0));
+    }
+
+    #[test]
+    fn test_json() {
+        let json = benchmark_records_to_json(&[record("Heap Sort"), record("Merge Sort")]);
+        let expected = concat!(
+            "[\n",
+            "  {\"algorithm\": \"Heap Sort\", \"distribution\": \"random\", \"size\": 1000, \"repetitions\": 5, \"comparisons\": 8700, \"swaps\": 9100, \"moves\": 18200, \"duration_p50_ns\": 1500, \"duration_p90_ns\": 2500, \"duration_p99_ns\": 3000, \"duration_ci_low_ns\": 1400, \"duration_ci_high_ns\": 1700},\n",
+            "  {\"algorithm\": \"Merge Sort\", \"distribution\": \"random\", \"size\": 1000, \"repetitions\": 5, \"comparisons\": 8700, \"swaps\": 9100, \"moves\": 18200, \"duration_p50_ns\": 1500, \"duration_p90_ns\": 2500, \"duration_p99_ns\": 3000, \"duration_ci_low_ns\": 1400, \"duration_ci_high_ns\": 1700}\n",
+            "]\n",
+        );
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_json_empty() {
+        assert_eq!(benchmark_records_to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_json_escapes_strings() {
+        let json = benchmark_records_to_json(&[record("Sort \"fast\"\\\n\u{1}")]);
+        assert!(json.contains(r#""algorithm": "Sort \"fast\"\\\n\u0001""#));
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = benchmark_records_to_csv(&[record("Heap Sort")]);
+        let expected = concat!(
+            "algorithm,distribution,size,repetitions,comparisons,swaps,moves,duration_p50_ns,duration_p90_ns,duration_p99_ns,duration_ci_low_ns,duration_ci_high_ns\n",
+            "Heap Sort,random,1000,5,8700,9100,18200,1500,2500,3000,1400,1700\n",
+        );
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        let csv = benchmark_records_to_csv(&[record("Partial Sort (k = 100, \"top\")")]);
+        assert!(csv.lines().nth(1).unwrap().starts_with("\"Partial Sort (k = 100, \"\"top\"\")\",random,"));
+    }
+
+    #[test]
+    fn test_write_benchmark_records() {
+        let name = format!("reporting_tests_{}", std::process::id());
+        write_benchmark_records(&[record("Heap Sort")], &name).unwrap();
+
+        let json_path = format!("target/benchmarks/{}.json", name);
+        let csv_path = format!("target/benchmarks/{}.csv", name);
+        assert_eq!(fs::read_to_string(&json_path).unwrap(), benchmark_records_to_json(&[record("Heap Sort")]));
+        assert_eq!(fs::read_to_string(&csv_path).unwrap(), benchmark_records_to_csv(&[record("Heap Sort")]));
+        fs::remove_file(json_path).unwrap();
+        fs::remove_file(csv_path).unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod visualization_tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use crate::sorting::bubble_sort::bubble_sort_tracked;
+    use crate::sorting::merge_sort::merge_sort_tracked;
+    use crate::util::data_generation::generate_random_vec;
+    use crate::util::visualization::{trace_sort, SortAnimation};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rust_dsa_visualization_tests_{}_{}", std::process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_trace_sort_leaves_input_unchanged() {
+        let initial = vec![3, 1, 2];
+        let trace = trace_sort(&initial, bubble_sort_tracked);
+
+        assert_eq!(initial, vec![3, 1, 2]);
+        assert!(!trace.is_empty());
+        assert_eq!(trace.replay(&initial, trace.len()), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_frame_positions_every_event() {
+        let animation = SortAnimation::new();
+        assert_eq!(animation.frame_positions(4), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_frame_positions_with_frame_step() {
+        let animation = SortAnimation::new().frame_step(3);
+        assert_eq!(animation.frame_positions(10), vec![0, 3, 6, 9, 10]);
+        assert_eq!(animation.frame_positions(9), vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn test_frame_positions_capped_by_max_frames() {
+        let animation = SortAnimation::new().max_frames(10);
+        let positions = animation.frame_positions(1000);
+
+        assert_eq!(positions.len(), 11);
+        assert_eq!(positions.first(), Some(&0));
+        assert_eq!(positions.last(), Some(&1000));
+    }
+
+    #[test]
+    fn test_frame_positions_empty_trace() {
+        let animation = SortAnimation::new();
+        assert_eq!(animation.frame_positions(0), vec![0]);
+    }
+
+    #[test]
+    fn test_render_gif() {
+        let dir = temp_dir("gif");
+        let path = dir.join("bubble_sort.gif");
+        let initial = vec![5, -2, 4, 1, 3];
+        let trace = trace_sort(&initial, bubble_sort_tracked);
+
+        let frames = SortAnimation::new().size(160, 120).frame_delay(10).render_gif(&initial, &trace, &path).unwrap();
+
+        assert_eq!(frames, trace.len() + 1);
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_png_frames() {
+        let dir = temp_dir("png");
+        let initial = generate_random_vec(50);
+        let trace = trace_sort(&initial, merge_sort_tracked);
+
+        let frames = SortAnimation::new().size(160, 120).max_frames(4).render_png_frames(&initial, &trace, &dir).unwrap();
+
+        assert_eq!(frames, 5);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 5);
+        assert!(dir.join("frame_00000.png").exists());
+        assert!(dir.join("frame_00004.png").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
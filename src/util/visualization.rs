@@ -0,0 +1,207 @@
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::error::Error;
+use std::fs;
+use std::iter::once;
+use std::path::Path;
+use crate::sorting::sort_trace::{SortEvent, SortTrace};
+use crate::sorting::sort_tracker::SortTracker;
+
+const BAR_COLOR: RGBColor = RGBColor(160, 160, 160);
+const COMPARE_COLOR: RGBColor = RGBColor(40, 110, 220);
+const SWAP_COLOR: RGBColor = RGBColor(220, 50, 40);
+const WRITE_COLOR: RGBColor = RGBColor(40, 170, 70);
+
+/// Runs `sort` on a copy of `initial` with a tracing `SortTracker` and returns
+/// the recorded trace, ready to be rendered by `SortAnimation`.
+pub fn trace_sort<T: Clone, F: FnOnce(&mut [T], &mut SortTracker)>(initial: &[T], sort: F) -> SortTrace {
+    let mut arr = initial.to_vec();
+    let mut tracker = SortTracker::with_trace();
+    sort(&mut arr, &mut tracker);
+    tracker.get_trace().cloned().unwrap_or_default()
+}
+
+/// Renders a traced sort as bar-chart frames, either as an animated GIF or as
+/// a numbered PNG sequence.
+///
+/// Each frame shows the array after a batch of events, with the bars touched
+/// by that batch highlighted: blue for compares, red for swaps and moves and
+/// green for writes. A frame is drawn every `frame_step` events, widened if
+/// needed so that no more than `max_frames` frames are drawn, which keeps the
+/// output manageable for large inputs. The first frame is always the initial
+/// array and the last is always the sorted one.
+pub struct SortAnimation {
+    width: u32,
+    height: u32,
+    frame_step: usize,
+    max_frames: usize,
+    frame_delay_ms: u32,
+    title: String,
+}
+
+impl Default for SortAnimation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SortAnimation {
+    /// Creates an 800x600 animation with a frame for every event, capped at
+    /// 500 frames, shown for 50 ms each.
+    pub fn new() -> Self {
+        SortAnimation {
+            width: 800,
+            height: 600,
+            frame_step: 1,
+            max_frames: 500,
+            frame_delay_ms: 50,
+            title: String::new(),
+        }
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Draws a frame every `events` events. Values below 1 are treated as 1.
+    pub fn frame_step(mut self, events: usize) -> Self {
+        self.frame_step = events.max(1);
+        self
+    }
+
+    /// Caps the number of frames, not counting the final one. Values below 1
+    /// are treated as 1.
+    pub fn max_frames(mut self, frames: usize) -> Self {
+        self.max_frames = frames.max(1);
+        self
+    }
+
+    /// Sets how long each GIF frame is shown.
+    pub fn frame_delay(mut self, milliseconds: u32) -> Self {
+        self.frame_delay_ms = milliseconds;
+        self
+    }
+
+    /// Sets a caption drawn above every frame, followed by the step number.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Returns the number of events applied before each frame is drawn.
+    pub fn frame_positions(&self, event_count: usize) -> Vec<usize> {
+        let step = self.frame_step.max(event_count.div_ceil(self.max_frames));
+        let mut positions: Vec<usize> = (0..event_count).step_by(step).chain(once(event_count)).collect();
+        positions.dedup();
+        positions
+    }
+
+    /// Writes the animation to `path` as a GIF and returns the number of frames.
+    pub fn render_gif<T: Copy + Into<f64>, P: AsRef<Path>>(&self, initial: &[T], trace: &SortTrace, path: P) -> Result<usize, Box<dyn Error>> {
+        let root = BitMapBackend::gif(path.as_ref(), (self.width, self.height), self.frame_delay_ms)?.into_drawing_area();
+        self.render(initial, trace, |values, highlights, position| {
+            self.draw_frame(&root, values, highlights, position, trace.len())?;
+            root.present()?;
+            Ok(())
+        })
+    }
+
+    /// Writes one PNG per frame into `dir` as `frame_00000.png`,
+    /// `frame_00001.png`, ... and returns the number of frames.
+    pub fn render_png_frames<T: Copy + Into<f64>, P: AsRef<Path>>(&self, initial: &[T], trace: &SortTrace, dir: P) -> Result<usize, Box<dyn Error>> {
+        fs::create_dir_all(dir.as_ref())?;
+        let mut frame: usize = 0;
+        self.render(initial, trace, |values, highlights, position| {
+            let filepath = dir.as_ref().join(format!("frame_{:05}.png", frame));
+            let root = BitMapBackend::new(&filepath, (self.width, self.height)).into_drawing_area();
+            self.draw_frame(&root, values, highlights, position, trace.len())?;
+            root.present()?;
+            frame += 1;
+            Ok(())
+        })
+    }
+
+    // Replays the trace and calls `draw` for every frame with the bar values,
+    // the highlight colour of each bar and the number of events applied.
+    fn render<T, D>(&self, initial: &[T], trace: &SortTrace, mut draw: D) -> Result<usize, Box<dyn Error>>
+    where
+        T: Copy + Into<f64>,
+        D: FnMut(&[f64], &[Option<RGBColor>], usize) -> Result<(), Box<dyn Error>>,
+    {
+        let positions = self.frame_positions(trace.len());
+        let mut replay = trace.replayer(initial);
+        let mut highlights: Vec<Option<RGBColor>> = vec![None; initial.len()];
+
+        for &position in &positions {
+            highlights.iter_mut().for_each(|highlight| *highlight = None);
+            while replay.position() < position {
+                if let Some(event) = replay.step_forward() {
+                    highlight(&mut highlights, event);
+                }
+            }
+
+            let values: Vec<f64> = replay.state().iter().map(|&value| value.into()).collect();
+            draw(&values, &highlights, position)?;
+        }
+
+        Ok(positions.len())
+    }
+
+    fn draw_frame<DB: DrawingBackend>(&self, root: &DrawingArea<DB, Shift>, values: &[f64], highlights: &[Option<RGBColor>], position: usize, event_count: usize) -> Result<(), Box<dyn Error>>
+    where
+        DB::ErrorType: 'static,
+    {
+        root.fill(&WHITE)?;
+
+        let min = values.iter().copied().fold(0.0, f64::min);
+        let max = values.iter().copied().fold(0.0, f64::max).max(min + 1.0);
+
+        let mut builder = ChartBuilder::on(root);
+        builder.margin(10);
+        if !self.title.is_empty() {
+            builder.caption(format!("{} (step {} / {})", self.title, position, event_count), ("sans-serif", 24).into_font());
+        }
+        let mut chart = builder.build_cartesian_2d(0..values.len().max(1), min..max)?;
+
+        chart.draw_series(values.iter().enumerate().map(|(index, &value)| {
+            let color = highlights[index].unwrap_or(BAR_COLOR);
+            Rectangle::new([(index, 0.0), (index + 1, value)], color.filled())
+        }))?;
+
+        Ok(())
+    }
+}
+
+// Colours the bars an event touched. Swaps and moves take precedence over
+// writes, which take precedence over compares.
+fn highlight(highlights: &mut [Option<RGBColor>], event: SortEvent) {
+    let mut mark = |index: usize, color: RGBColor| {
+        let rank = |color: Option<RGBColor>| match color {
+            Some(SWAP_COLOR) => 3,
+            Some(WRITE_COLOR) => 2,
+            Some(COMPARE_COLOR) => 1,
+            _ => 0,
+        };
+        if rank(Some(color)) > rank(highlights[index]) {
+            highlights[index] = Some(color);
+        }
+    };
+
+    match event {
+        SortEvent::Compare { index1, index2, .. } => {
+            mark(index1, COMPARE_COLOR);
+            mark(index2, COMPARE_COLOR);
+        }
+        SortEvent::Swap { index1, index2 } => {
+            mark(index1, SWAP_COLOR);
+            mark(index2, SWAP_COLOR);
+        }
+        SortEvent::Move { from, to } => {
+            mark(from, SWAP_COLOR);
+            mark(to, SWAP_COLOR);
+        }
+        SortEvent::Write { index } => mark(index, WRITE_COLOR),
+    }
+}